@@ -15,6 +15,7 @@ wasmtime::component::bindgen!({
     async: {
         only_imports: [],
     },
+    trappable_imports: true,
     with: {
         "wasi:webgpu/frame-buffer/surface": FBSurfaceArc,
         "wasi:webgpu/frame-buffer/frame-buffer": FBBuffer,
@@ -50,7 +51,10 @@ impl DrawApi for FBSurfaceArc {
         self.0.lock().unwrap().present()
     }
 
-    fn display_api_ready(&mut self, display_api: &Box<dyn DisplayApi + Send + Sync>) {
+    fn display_api_ready(
+        &mut self,
+        display_api: &Box<dyn DisplayApi + Send + Sync>,
+    ) -> wasmtime::Result<()> {
         self.0.lock().unwrap().display_api_ready(display_api)
     }
 }
@@ -63,8 +67,10 @@ impl DrawApi for FBSurfaceArc {
 
 impl DrawApi for FBSurface {
     fn get_current_buffer(&mut self) -> wasmtime::Result<GraphicsContextBuffer> {
-        let surface = self.surface.as_mut().unwrap();
-        let buff = surface.buffer_mut().unwrap();
+        let Some(surface) = self.surface.as_mut() else {
+            return Err(wasmtime::Error::msg("The display isn't ready yet"));
+        };
+        let buff = surface.buffer_mut().map_err(softbuffer_error)?;
         // TODO: use ouroboros?
         let buff: softbuffer::Buffer<'static, GraphicsContext, GraphicsContext> =
             unsafe { mem::transmute(buff) };
@@ -75,19 +81,24 @@ impl DrawApi for FBSurface {
     }
 
     fn present(&mut self) -> wasmtime::Result<()> {
-        self.surface
-            .as_mut()
-            .unwrap()
+        let Some(surface) = self.surface.as_mut() else {
+            return Err(wasmtime::Error::msg("The display isn't ready yet"));
+        };
+        surface
             .buffer_mut()
-            .unwrap()
+            .map_err(softbuffer_error)?
             .present()
-            .unwrap();
-        Ok(())
+            .map_err(softbuffer_error)
     }
 
-    fn display_api_ready(&mut self, display: &Box<dyn DisplayApi + Send + Sync>) {
-        let context = softbuffer::Context::new(display.display_handle().unwrap()).unwrap();
-        let surface = softbuffer::Surface::new(&context, display.window_handle().unwrap()).unwrap();
+    fn display_api_ready(
+        &mut self,
+        display: &Box<dyn DisplayApi + Send + Sync>,
+    ) -> wasmtime::Result<()> {
+        let context =
+            softbuffer::Context::new(display.display_handle()?).map_err(softbuffer_error)?;
+        let surface = softbuffer::Surface::new(&context, display.window_handle()?)
+            .map_err(softbuffer_error)?;
 
         // TODO: use ouroboros?
         let mut surface: softbuffer::Surface<DisplayHandle<'static>, WindowHandle<'static>> =
@@ -95,21 +106,21 @@ impl DrawApi for FBSurface {
 
         // softbuffer requires setting the size before presenting.
         let _ = surface.resize(
-            display
-                .width()
-                .try_into()
-                .unwrap_or(NonZeroU32::new(1).unwrap()),
-            display
-                .height()
-                .try_into()
-                .unwrap_or(NonZeroU32::new(1).unwrap()),
+            display.width().try_into().unwrap_or(NonZeroU32::MIN),
+            display.height().try_into().unwrap_or(NonZeroU32::MIN),
         );
         self.surface = Some(surface);
+        Ok(())
     }
 }
 
+// softbuffer's errors aren't `Send + Sync`, so they can't be wrapped as is.
+fn softbuffer_error(error: softbuffer::SoftBufferError) -> wasmtime::Error {
+    wasmtime::Error::msg(error.to_string())
+}
+
 pub struct FBBuffer {
-    // Never none
+    // Only none once the frame buffer is dropped.
     buffer: Arc<Mutex<Option<softbuffer::Buffer<'static, GraphicsContext, GraphicsContext>>>>,
 }
 // TODO: ensure safety
@@ -144,22 +155,23 @@ pub trait WasiFrameBufferView: WasiView {}
 impl frame_buffer::Host for dyn WasiFrameBufferView + '_ {}
 
 impl frame_buffer::HostSurface for dyn WasiFrameBufferView + '_ {
-    fn new(&mut self) -> Resource<crate::wasi::webgpu::frame_buffer::Surface> {
-        self.table().push(FBSurfaceArc::new()).unwrap()
+    fn new(&mut self) -> wasmtime::Result<Resource<crate::wasi::webgpu::frame_buffer::Surface>> {
+        Ok(self.table().push(FBSurfaceArc::new())?)
     }
 
     fn connect_graphics_context(
         &mut self,
         surface: Resource<FBSurfaceArc>,
         graphics_context: Resource<GraphicsContext>,
-    ) {
-        let surface = FBSurfaceArc(Arc::clone(&self.table().get(&surface).unwrap().0));
-        let graphics_context = self.table().get_mut(&graphics_context).unwrap();
-        graphics_context.connect_draw_api(Box::new(surface));
+    ) -> wasmtime::Result<()> {
+        let surface = FBSurfaceArc(Arc::clone(&self.table().get(&surface)?.0));
+        let graphics_context = self.table().get_mut(&graphics_context)?;
+        graphics_context.connect_draw_api(Box::new(surface))
     }
 
-    fn drop(&mut self, _rep: Resource<FBSurfaceArc>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, surface: Resource<FBSurfaceArc>) -> wasmtime::Result<()> {
+        self.table().delete(surface)?;
+        Ok(())
     }
 }
 
@@ -167,38 +179,57 @@ impl frame_buffer::HostFrameBuffer for dyn WasiFrameBufferView + '_ {
     fn from_graphics_buffer(
         &mut self,
         buffer: Resource<GraphicsContextBuffer>,
-    ) -> Resource<FBBuffer> {
-        let host_buffer: GraphicsContextBuffer = self.table().delete(buffer).unwrap();
-        let host_buffer: FBBuffer = host_buffer.inner_type();
-        self.table().push(host_buffer).unwrap()
-    }
-
-    fn length(&mut self, buffer: Resource<FBBuffer>) -> u32 {
-        let buffer = self.table().get(&buffer).unwrap();
-        let len = buffer.buffer.lock().unwrap().as_ref().unwrap().len();
-        len as u32
-    }
-
-    fn get(&mut self, buffer: Resource<FBBuffer>, i: u32) -> u32 {
-        let buffer = self.table().get(&buffer).unwrap();
-        *buffer
-            .buffer
-            .lock()
-            .unwrap()
-            .as_ref()
-            .unwrap()
-            .get(i as usize)
-            .unwrap()
+    ) -> wasmtime::Result<Resource<FBBuffer>> {
+        let host_buffer: GraphicsContextBuffer = self.table().delete(buffer)?;
+        let host_buffer: FBBuffer = host_buffer.inner_type()?;
+        Ok(self.table().push(host_buffer)?)
+    }
+
+    fn length(&mut self, buffer: Resource<FBBuffer>) -> wasmtime::Result<u32> {
+        let buffer = self.table().get(&buffer)?;
+        let buffer = buffer.buffer.lock().unwrap();
+        let Some(buffer) = buffer.as_ref() else {
+            return Err(released());
+        };
+        Ok(buffer.len() as u32)
+    }
+
+    fn get(&mut self, buffer: Resource<FBBuffer>, i: u32) -> wasmtime::Result<u32> {
+        let buffer = self.table().get(&buffer)?;
+        let buffer = buffer.buffer.lock().unwrap();
+        let Some(buffer) = buffer.as_ref() else {
+            return Err(released());
+        };
+        match buffer.get(i as usize) {
+            Some(val) => Ok(*val),
+            None => Err(out_of_bounds(i)),
+        }
     }
 
-    fn set(&mut self, buffer: Resource<FBBuffer>, i: u32, val: u32) {
-        let buffer = self.table().get_mut(&buffer).unwrap();
-        buffer.buffer.lock().unwrap().as_mut().unwrap()[i as usize] = val as u32;
+    fn set(&mut self, buffer: Resource<FBBuffer>, i: u32, val: u32) -> wasmtime::Result<()> {
+        let buffer = self.table().get_mut(&buffer)?;
+        let mut buffer = buffer.buffer.lock().unwrap();
+        let Some(buffer) = buffer.as_mut() else {
+            return Err(released());
+        };
+        match buffer.get_mut(i as usize) {
+            Some(pixel) => *pixel = val,
+            None => return Err(out_of_bounds(i)),
+        }
+        Ok(())
     }
 
     fn drop(&mut self, frame_buffer: Resource<FBBuffer>) -> wasmtime::Result<()> {
-        let frame_buffer = self.table().delete(frame_buffer).unwrap();
+        let frame_buffer = self.table().delete(frame_buffer)?;
         frame_buffer.buffer.lock().unwrap().take();
         Ok(())
     }
 }
+
+fn released() -> wasmtime::Error {
+    wasmtime::Error::msg("Frame buffer was already released")
+}
+
+fn out_of_bounds(i: u32) -> wasmtime::Error {
+    wasmtime::Error::msg(format!("Frame buffer index {i} is out of bounds"))
+}
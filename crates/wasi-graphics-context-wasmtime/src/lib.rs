@@ -11,6 +11,7 @@ wasmtime::component::bindgen!({
     path: "../../wit/",
     world: "example",
     async: false,
+    trappable_imports: true,
     with: {
         "wasi:webgpu/graphics-context/graphics-context": GraphicsContext,
         "wasi:webgpu/graphics-context/graphics-context-buffer": GraphicsContextBuffer,
@@ -34,11 +35,15 @@ impl GraphicsContext {
         Ok(())
     }
 
-    pub fn connect_display_api(&mut self, display_api: Box<dyn DisplayApi + Send + Sync>) {
+    pub fn connect_display_api(
+        &mut self,
+        display_api: Box<dyn DisplayApi + Send + Sync>,
+    ) -> wasmtime::Result<()> {
         if let Some(draw_api) = &mut self.draw_api {
-            draw_api.display_api_ready(&display_api)
+            draw_api.display_api_ready(&display_api)?;
         }
         self.display_api = Some(display_api);
+        Ok(())
     }
 
     // pub fn resize(&mut self, height: u32, width: u32) {
@@ -46,11 +51,15 @@ impl GraphicsContext {
     //     self.width = Some(width);
     // }
 
-    pub fn connect_draw_api(&mut self, mut draw_api: Box<dyn DrawApi + Send + Sync>) {
+    pub fn connect_draw_api(
+        &mut self,
+        mut draw_api: Box<dyn DrawApi + Send + Sync>,
+    ) -> wasmtime::Result<()> {
         if let Some(display_api) = &self.display_api {
-            draw_api.display_api_ready(&*display_api)
+            draw_api.display_api_ready(display_api)?;
         }
         self.draw_api = Some(draw_api);
+        Ok(())
     }
 }
 
@@ -76,7 +85,10 @@ impl HasWindowHandle for GraphicsContext {
 pub trait DrawApi {
     fn get_current_buffer(&mut self) -> wasmtime::Result<GraphicsContextBuffer>;
     fn present(&mut self) -> wasmtime::Result<()>;
-    fn display_api_ready(&mut self, display_api: &Box<dyn DisplayApi + Send + Sync>);
+    fn display_api_ready(
+        &mut self,
+        display_api: &Box<dyn DisplayApi + Send + Sync>,
+    ) -> wasmtime::Result<()>;
 }
 
 pub trait DisplayApi: HasDisplayHandle + HasWindowHandle {
@@ -99,11 +111,17 @@ where
 }
 
 impl GraphicsContextBuffer {
-    pub fn inner_type<T>(self) -> T
+    /// Fails when the buffer came from a different draw api.
+    pub fn inner_type<T>(self) -> wasmtime::Result<T>
     where
         T: 'static,
     {
-        **self.buffer.downcast::<Box<T>>().unwrap()
+        match self.buffer.downcast::<Box<T>>() {
+            Ok(buffer) => Ok(**buffer),
+            Err(_) => Err(wasmtime::Error::msg(
+                "Graphics context buffer belongs to a different draw api",
+            )),
+        }
     }
 }
 
@@ -128,34 +146,41 @@ pub trait WasiGraphicsContextView: WasiView {}
 impl graphics_context::Host for dyn WasiGraphicsContextView + '_ {}
 
 impl graphics_context::HostGraphicsContext for dyn WasiGraphicsContextView + '_ {
-    fn new(&mut self) -> Resource<GraphicsContext> {
-        self.table().push(GraphicsContext::new()).unwrap()
+    fn new(&mut self) -> wasmtime::Result<Resource<GraphicsContext>> {
+        Ok(self.table().push(GraphicsContext::new())?)
     }
 
-    fn configure(&mut self, context: Resource<GraphicsContext>, desc: ConfigureContextDesc) {
-        let graphics_context = self.table().get_mut(&context).unwrap();
-        graphics_context.configure(desc).unwrap();
+    fn configure(
+        &mut self,
+        context: Resource<GraphicsContext>,
+        desc: ConfigureContextDesc,
+    ) -> wasmtime::Result<()> {
+        let graphics_context = self.table().get_mut(&context)?;
+        graphics_context.configure(desc)
     }
 
     fn get_current_buffer(
         &mut self,
         context: Resource<GraphicsContext>,
-    ) -> Resource<GraphicsContextBuffer> {
-        let context_kind = self.table().get_mut(&context).unwrap();
+    ) -> wasmtime::Result<Resource<GraphicsContextBuffer>> {
+        let context_kind = self.table().get_mut(&context)?;
         let next_frame = context_kind
             .draw_api
             .as_mut()
-            .expect("draw_api not set")
-            .get_current_buffer()
-            .unwrap();
-        let next_frame = self.table().push(next_frame).unwrap();
-        next_frame
+            .ok_or_else(draw_api_not_set)?
+            .get_current_buffer()?;
+        let next_frame = self.table().push(next_frame)?;
+        Ok(next_frame)
     }
 
-    fn present(&mut self, context: Resource<GraphicsContext>) {
-        let context = self.table().get_mut(&context).unwrap();
+    fn present(&mut self, context: Resource<GraphicsContext>) -> wasmtime::Result<()> {
+        let context = self.table().get_mut(&context)?;
         // context.display_api.as_mut().unwrap().present().unwrap();
-        context.draw_api.as_mut().unwrap().present().unwrap();
+        context
+            .draw_api
+            .as_mut()
+            .ok_or_else(draw_api_not_set)?
+            .present()
     }
 
     fn drop(&mut self, _graphics_context: Resource<GraphicsContext>) -> wasmtime::Result<()> {
@@ -165,7 +190,12 @@ impl graphics_context::HostGraphicsContext for dyn WasiGraphicsContextView + '_
 }
 
 impl graphics_context::HostGraphicsContextBuffer for dyn WasiGraphicsContextView + '_ {
-    fn drop(&mut self, _rep: Resource<GraphicsContextBuffer>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, buffer: Resource<GraphicsContextBuffer>) -> wasmtime::Result<()> {
+        self.table().delete(buffer)?;
+        Ok(())
     }
 }
+
+fn draw_api_not_set() -> wasmtime::Error {
+    wasmtime::Error::msg("Graphics context isn't connected to a draw api")
+}
@@ -12,15 +12,13 @@ impl animation_frame::Host for dyn WasiMiniCanvasView + '_ {
     async fn listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<AnimationFrameListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<AnimationFrameListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.frame_sender.new_receiver();
-        self.table()
-            .push(AnimationFrameListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(AnimationFrameListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 }
 
@@ -28,12 +26,15 @@ impl animation_frame::HostFrameListener for dyn WasiMiniCanvasView + '_ {
     fn subscribe(
         &mut self,
         frame_listener: Resource<AnimationFrameListener>,
-    ) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), frame_listener).unwrap()
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), frame_listener)
     }
-    fn get(&mut self, frame_listener: Resource<AnimationFrameListener>) -> Option<FrameEvent> {
-        let frame_listener = self.table().get(&frame_listener).unwrap();
-        frame_listener.data.lock().unwrap().take()
+    fn get(
+        &mut self,
+        frame_listener: Resource<AnimationFrameListener>,
+    ) -> wasmtime::Result<Option<FrameEvent>> {
+        let frame_listener = self.table().get(&frame_listener)?;
+        Ok(frame_listener.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<AnimationFrameListener>) -> wasmtime::Result<()> {
         Ok(())
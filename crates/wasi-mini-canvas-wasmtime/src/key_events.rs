@@ -12,39 +12,38 @@ impl key_events::Host for dyn WasiMiniCanvasView + '_ {
     async fn up_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<KeyUpListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<KeyUpListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.key_up_sender.new_receiver();
-        self.table()
-            .push(KeyUpListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(KeyUpListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 
     async fn down_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<KeyDownListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<KeyDownListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.key_down_sender.new_receiver();
-        self.table()
-            .push(KeyDownListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(KeyDownListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 }
 
 impl key_events::HostKeyUpListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, key_up: Resource<KeyUpListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), key_up).unwrap()
+    fn subscribe(
+        &mut self,
+        key_up: Resource<KeyUpListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), key_up)
     }
-    fn get(&mut self, key_up: Resource<KeyUpListener>) -> Option<KeyEvent> {
-        let key_up = self.table().get(&key_up).unwrap();
-        key_up.data.lock().unwrap().take()
+    fn get(&mut self, key_up: Resource<KeyUpListener>) -> wasmtime::Result<Option<KeyEvent>> {
+        let key_up = self.table().get(&key_up)?;
+        Ok(key_up.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<KeyUpListener>) -> wasmtime::Result<()> {
         Ok(())
@@ -65,12 +64,15 @@ impl wasmtime_wasi::Subscribe for KeyUpListener {
     }
 }
 impl key_events::HostKeyDownListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, key_down: Resource<KeyDownListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), key_down).unwrap()
+    fn subscribe(
+        &mut self,
+        key_down: Resource<KeyDownListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), key_down)
     }
-    fn get(&mut self, key_down: Resource<KeyDownListener>) -> Option<KeyEvent> {
-        let key_down = self.table().get(&key_down).unwrap();
-        key_down.data.lock().unwrap().take()
+    fn get(&mut self, key_down: Resource<KeyDownListener>) -> wasmtime::Result<Option<KeyEvent>> {
+        let key_down = self.table().get(&key_down)?;
+        Ok(key_down.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<KeyDownListener>) -> wasmtime::Result<()> {
         Ok(())
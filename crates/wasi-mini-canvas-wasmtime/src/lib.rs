@@ -67,6 +67,7 @@ wasmtime::component::bindgen!({
             // "resize-listener",
         ],
     },
+    trappable_imports: true,
     with: {
         "wasi:io": wasmtime_wasi::bindings::io,
         "wasi:webgpu/pointer-events/pointer-up-listener": pointer_events::PointerUpListener,
@@ -297,45 +298,43 @@ impl mini_canvas::Host for dyn WasiMiniCanvasView + '_ {}
 
 #[async_trait::async_trait]
 impl mini_canvas::HostMiniCanvas for dyn WasiMiniCanvasView + '_ {
-    fn new(&mut self, desc: MiniCanvasDesc) -> Resource<MiniCanvasArc> {
+    fn new(&mut self, desc: MiniCanvasDesc) -> wasmtime::Result<Resource<MiniCanvasArc>> {
         let canvas = self.create_canvas(desc);
         let mini_canvas = MiniCanvasArc(Arc::new(canvas));
-        self.table().push(mini_canvas).unwrap()
+        Ok(self.table().push(mini_canvas)?)
     }
 
     fn connect_graphics_context(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
         context: Resource<GraphicsContext>,
-    ) {
-        let mini_canvas = self.table().get(&mini_canvas).unwrap().clone();
-        let graphics_context = self.table().get_mut(&context).unwrap();
+    ) -> wasmtime::Result<()> {
+        let mini_canvas = self.table().get(&mini_canvas)?.clone();
+        let graphics_context = self.table().get_mut(&context)?;
 
-        graphics_context.connect_display_api(Box::new(mini_canvas));
+        graphics_context.connect_display_api(Box::new(mini_canvas))
     }
 
     fn resize_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<ResizeListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<ResizeListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.canvas_resize_sender.new_receiver();
-        self.table()
-            .push(ResizeListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(ResizeListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 
-    fn height(&mut self, mini_canvas: Resource<MiniCanvasArc>) -> u32 {
-        let mini_canvas = self.table().get(&mini_canvas).unwrap();
-        mini_canvas.height()
+    fn height(&mut self, mini_canvas: Resource<MiniCanvasArc>) -> wasmtime::Result<u32> {
+        let mini_canvas = self.table().get(&mini_canvas)?;
+        Ok(mini_canvas.height())
     }
 
-    fn width(&mut self, mini_canvas: Resource<MiniCanvasArc>) -> u32 {
-        let mini_canvas = self.table().get(&mini_canvas).unwrap();
-        mini_canvas.width()
+    fn width(&mut self, mini_canvas: Resource<MiniCanvasArc>) -> wasmtime::Result<u32> {
+        let mini_canvas = self.table().get(&mini_canvas)?;
+        Ok(mini_canvas.width())
     }
 
     fn drop(&mut self, _self_: Resource<MiniCanvasArc>) -> wasmtime::Result<()> {
@@ -344,12 +343,18 @@ impl mini_canvas::HostMiniCanvas for dyn WasiMiniCanvasView + '_ {
 }
 
 impl mini_canvas::HostResizeListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, pointer_down: Resource<ResizeListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), pointer_down).unwrap()
+    fn subscribe(
+        &mut self,
+        pointer_down: Resource<ResizeListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), pointer_down)
     }
-    fn get(&mut self, pointer_down: Resource<ResizeListener>) -> Option<ResizeEvent> {
-        let pointer_down = self.table().get(&pointer_down).unwrap();
-        pointer_down.data.lock().unwrap().take()
+    fn get(
+        &mut self,
+        pointer_down: Resource<ResizeListener>,
+    ) -> wasmtime::Result<Option<ResizeEvent>> {
+        let pointer_down = self.table().get(&pointer_down)?;
+        Ok(pointer_down.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<ResizeListener>) -> wasmtime::Result<()> {
         Ok(())
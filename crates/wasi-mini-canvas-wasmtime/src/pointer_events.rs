@@ -12,53 +12,53 @@ impl pointer_events::Host for dyn WasiMiniCanvasView + '_ {
     async fn up_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<PointerUpListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<PointerUpListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.pointer_up_sender.new_receiver();
-        self.table()
-            .push(PointerUpListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(PointerUpListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 
     async fn down_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<PointerDownListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<PointerDownListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.pointer_down_sender.new_receiver();
-        self.table()
-            .push(PointerDownListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(PointerDownListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 
     async fn move_listener(
         &mut self,
         mini_canvas: Resource<MiniCanvasArc>,
-    ) -> Resource<PointerMoveListener> {
-        let canvas = &self.table().get(&mini_canvas).unwrap().0;
+    ) -> wasmtime::Result<Resource<PointerMoveListener>> {
+        let canvas = &self.table().get(&mini_canvas)?.0;
         let receiver = canvas.pointer_move_sender.new_receiver();
-        self.table()
-            .push(PointerMoveListener {
-                receiver,
-                data: Default::default(),
-            })
-            .unwrap()
+        Ok(self.table().push(PointerMoveListener {
+            receiver,
+            data: Default::default(),
+        })?)
     }
 }
 
 impl pointer_events::HostPointerUpListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, pointer_up: Resource<PointerUpListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), pointer_up).unwrap()
+    fn subscribe(
+        &mut self,
+        pointer_up: Resource<PointerUpListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), pointer_up)
     }
-    fn get(&mut self, pointer_up: Resource<PointerUpListener>) -> Option<PointerEvent> {
-        let pointer_up = self.table().get(&pointer_up).unwrap();
-        pointer_up.data.lock().unwrap().take()
+    fn get(
+        &mut self,
+        pointer_up: Resource<PointerUpListener>,
+    ) -> wasmtime::Result<Option<PointerEvent>> {
+        let pointer_up = self.table().get(&pointer_up)?;
+        Ok(pointer_up.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<PointerUpListener>) -> wasmtime::Result<()> {
         Ok(())
@@ -80,12 +80,18 @@ impl wasmtime_wasi::Subscribe for PointerUpListener {
 }
 
 impl pointer_events::HostPointerDownListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, pointer_down: Resource<PointerDownListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), pointer_down).unwrap()
+    fn subscribe(
+        &mut self,
+        pointer_down: Resource<PointerDownListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), pointer_down)
     }
-    fn get(&mut self, pointer_down: Resource<PointerDownListener>) -> Option<PointerEvent> {
-        let pointer_down = self.table().get(&pointer_down).unwrap();
-        pointer_down.data.lock().unwrap().take()
+    fn get(
+        &mut self,
+        pointer_down: Resource<PointerDownListener>,
+    ) -> wasmtime::Result<Option<PointerEvent>> {
+        let pointer_down = self.table().get(&pointer_down)?;
+        Ok(pointer_down.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<PointerDownListener>) -> wasmtime::Result<()> {
         Ok(())
@@ -107,12 +113,18 @@ impl wasmtime_wasi::Subscribe for PointerDownListener {
 }
 
 impl pointer_events::HostPointerMoveListener for dyn WasiMiniCanvasView + '_ {
-    fn subscribe(&mut self, pointer_move: Resource<PointerMoveListener>) -> Resource<Pollable> {
-        wasmtime_wasi::subscribe(self.table(), pointer_move).unwrap()
+    fn subscribe(
+        &mut self,
+        pointer_move: Resource<PointerMoveListener>,
+    ) -> wasmtime::Result<Resource<Pollable>> {
+        wasmtime_wasi::subscribe(self.table(), pointer_move)
     }
-    fn get(&mut self, pointer_move: Resource<PointerMoveListener>) -> Option<PointerEvent> {
-        let pointer_move = self.table().get(&pointer_move).unwrap();
-        pointer_move.data.lock().unwrap().take()
+    fn get(
+        &mut self,
+        pointer_move: Resource<PointerMoveListener>,
+    ) -> wasmtime::Result<Option<PointerEvent>> {
+        let pointer_move = self.table().get(&pointer_move)?;
+        Ok(pointer_move.data.lock().unwrap().take())
     }
     fn drop(&mut self, _self_: Resource<PointerMoveListener>) -> wasmtime::Result<()> {
         Ok(())
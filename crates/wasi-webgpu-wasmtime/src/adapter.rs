@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+/// Keeps an adapter's wgpu-core id valid.
+///
+/// wgpu-core only releases an adapter while no device requested from it is alive, and ignores the drop otherwise.
+/// So every device's poller holds a clone until it dropped the device, and the id is released once that
+/// and the guest's adapter are gone.
+#[derive(Clone)]
+pub(crate) struct AdapterHandle(Arc<AdapterId>);
+
+struct AdapterId {
+    instance: Arc<wgpu_core::global::Global>,
+    id: wgpu_core::id::AdapterId,
+}
+
+impl AdapterHandle {
+    pub(crate) fn new(
+        instance: Arc<wgpu_core::global::Global>,
+        id: wgpu_core::id::AdapterId,
+    ) -> Self {
+        Self(Arc::new(AdapterId { instance, id }))
+    }
+
+    pub(crate) fn id(&self) -> wgpu_core::id::AdapterId {
+        self.0.id
+    }
+}
+
+impl Drop for AdapterId {
+    fn drop(&mut self) {
+        self.instance.adapter_drop::<crate::Backend>(self.id);
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::{Arc, Mutex, Weak};
+
+use crate::wasi::webgpu::webgpu;
+
+/// The host side of a buffer's mapping.
+pub(crate) struct MapState {
+    // Tracked here rather than asked of wgpu-core, so invalid transitions are caught before reaching it.
+    pub(crate) state: webgpu::GpuBufferMapState,
+    // Shared with every remote-buffer handed out by `get-mapped-range`, dropped on unmap.
+    pub(crate) mapping: Option<Arc<()>>,
+}
+
+impl MapState {
+    pub(crate) fn new(mapped_at_creation: bool) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            state: if mapped_at_creation {
+                webgpu::GpuBufferMapState::Mapped
+            } else {
+                webgpu::GpuBufferMapState::Unmapped
+            },
+            mapping: None,
+        }))
+    }
+
+    /// Forgets the mapping, leaving every remote-buffer of it detached.
+    pub(crate) fn unmap(&mut self) {
+        self.state = webgpu::GpuBufferMapState::Unmapped;
+        self.mapping.take();
+    }
+}
+
+type TrackedBuffer = (wgpu_core::id::BufferId, Weak<Mutex<MapState>>);
+
+/// The buffers a device created, so destroying the device can unmap them.
+#[derive(Clone, Default)]
+pub(crate) struct DeviceBuffers(Arc<Mutex<Vec<TrackedBuffer>>>);
+
+impl DeviceBuffers {
+    pub(crate) fn track(
+        &self,
+        buffer_id: wgpu_core::id::BufferId,
+        map_state: &Arc<Mutex<MapState>>,
+    ) {
+        let mut buffers = self.0.lock().unwrap();
+        buffers.retain(|(_, map_state)| map_state.strong_count() > 0);
+        buffers.push((buffer_id, Arc::downgrade(map_state)));
+    }
+
+    /// Unmaps every buffer that is still alive.
+    // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-destroy
+    pub(crate) fn unmap_all(&self, instance: &wgpu_core::global::Global) {
+        for (buffer_id, map_state) in self.0.lock().unwrap().iter() {
+            let Some(map_state) = map_state.upgrade() else {
+                continue;
+            };
+            let mut map_state = map_state.lock().unwrap();
+            if map_state.state != webgpu::GpuBufferMapState::Unmapped {
+                map_state.unmap();
+                // Pending maps are aborted by this as well, failures are moot as the device is going away.
+                let _ = instance.buffer_unmap::<crate::Backend>(*buffer_id);
+            }
+        }
+    }
+}
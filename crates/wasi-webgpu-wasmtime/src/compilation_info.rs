@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::ops::Range;
+
+use wgpu_core::pipeline::CreateShaderModuleError;
+
+use crate::wasi::webgpu::webgpu;
+
+/// Backs the `gpu-compilation-info` resource.
+pub struct CompilationInfo {
+    pub(crate) messages: Vec<CompilationMessage>,
+}
+
+/// Backs the `gpu-compilation-message` resource.
+#[derive(Clone, Debug)]
+pub struct CompilationMessage {
+    pub(crate) message: String,
+    pub(crate) type_: webgpu::GpuCompilationMessageType,
+    pub(crate) line_num: u64,
+    pub(crate) line_pos: u64,
+    pub(crate) offset: u64,
+    pub(crate) length: u64,
+}
+
+impl CompilationMessage {
+    fn error(source: &str, message: String, span: Option<Range<usize>>) -> Self {
+        // source: https://www.w3.org/TR/webgpu/#gpucompilationmessage
+        // Lines and positions are 1-based, everything is counted in UTF-16 code units, and all of it is 0 without a span.
+        let Some(span) = span.filter(|span| source.get(span.clone()).is_some()) else {
+            return Self {
+                message,
+                type_: webgpu::GpuCompilationMessageType::Error,
+                line_num: 0,
+                line_pos: 0,
+                offset: 0,
+                length: 0,
+            };
+        };
+        let prefix = &source[..span.start];
+        let line_start = prefix.rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+        Self {
+            message,
+            type_: webgpu::GpuCompilationMessageType::Error,
+            line_num: prefix.matches('\n').count() as u64 + 1,
+            line_pos: prefix[line_start..].encode_utf16().count() as u64 + 1,
+            offset: prefix.encode_utf16().count() as u64,
+            length: source[span].encode_utf16().count() as u64,
+        }
+    }
+}
+
+impl CompilationInfo {
+    /// Turns the error from creating a shader module out of `source` into compilation messages.
+    pub(crate) fn new(source: &str, error: Option<&CreateShaderModuleError>) -> Self {
+        let messages = match error {
+            None => vec![],
+            Some(CreateShaderModuleError::Parsing(error)) => vec![CompilationMessage::error(
+                source,
+                error.inner.message().to_string(),
+                error
+                    .inner
+                    .labels()
+                    .next()
+                    .and_then(|(span, _)| span.to_range()),
+            )],
+            Some(CreateShaderModuleError::Validation(error)) => vec![CompilationMessage::error(
+                source,
+                error_chain(error.inner.as_inner()),
+                error
+                    .inner
+                    .spans()
+                    .next()
+                    .and_then(|(span, _)| span.to_range()),
+            )],
+            #[cfg(feature = "glsl")]
+            Some(CreateShaderModuleError::ParsingGlsl(error)) => error
+                .inner
+                .errors
+                .iter()
+                .map(|error| {
+                    CompilationMessage::error(source, error.kind.to_string(), error.meta.to_range())
+                })
+                .collect(),
+            Some(error) => vec![CompilationMessage::error(source, error.to_string(), None)],
+        };
+        Self { messages }
+    }
+}
+
+// naga's validation errors only make sense with their causes attached.
+fn error_chain(error: &(dyn Error + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+        message.push_str(&format!(": {error}"));
+        source = error.source();
+    }
+    message
+}
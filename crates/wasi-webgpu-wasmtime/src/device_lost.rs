@@ -0,0 +1,68 @@
+use std::sync::Mutex;
+
+use futures::channel::oneshot;
+use futures::future::Shared;
+use futures::FutureExt;
+
+use crate::wasi::webgpu::webgpu;
+
+/// Backs the `gpu-device-lost-info` resource.
+#[derive(Clone, Debug)]
+pub struct DeviceLostInfo {
+    pub(crate) reason: webgpu::GpuDeviceLostReason,
+    pub(crate) message: String,
+}
+
+/// Resolves once the device is lost, just like `GPUDevice.lost` does in the browser.
+pub(crate) type DeviceLost = Shared<oneshot::Receiver<DeviceLostInfo>>;
+
+/// Creates a wgpu-core device lost closure, along with the future it resolves.
+pub(crate) fn device_lost_closure() -> (wgpu_core::device::DeviceLostClosure, DeviceLost) {
+    let (sender, receiver) = oneshot::channel();
+    // wgpu-core calls the closure exactly once, but only gives us a `Fn`.
+    let sender = Mutex::new(Some(sender));
+    let closure = wgpu_core::device::DeviceLostClosure::from_rust(Box::new(
+        move |reason: wgpu_types::DeviceLostReason, message: String| {
+            if let Some(sender) = sender.lock().unwrap().take() {
+                let _ = sender.send(DeviceLostInfo {
+                    reason: reason.into(),
+                    message,
+                });
+            }
+        },
+    ));
+    (closure, receiver.shared())
+}
+
+pub(crate) fn already_lost(message: String) -> DeviceLost {
+    let (sender, receiver) = oneshot::channel();
+    let _ = sender.send(DeviceLostInfo {
+        reason: webgpu::GpuDeviceLostReason::Unknown,
+        message,
+    });
+    receiver.shared()
+}
+
+pub struct DeviceLostListener {
+    pub(crate) lost: DeviceLost,
+}
+
+impl DeviceLostListener {
+    pub(crate) fn info(&self) -> Option<DeviceLostInfo> {
+        match self.lost.peek()? {
+            Ok(info) => Some(info.clone()),
+            // Closure was dropped without being called, so we don't know why the device is gone.
+            Err(oneshot::Canceled) => Some(DeviceLostInfo {
+                reason: webgpu::GpuDeviceLostReason::Unknown,
+                message: String::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl wasmtime_wasi::Subscribe for DeviceLostListener {
+    async fn ready(&mut self) {
+        let _ = self.lost.clone().await;
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+
+use crate::adapter::AdapterHandle;
+
+/// Drives wgpu-core callbacks, like buffer map callbacks, for a single device.
+///
+/// Polling happens on a background thread so that waiting on the GPU doesn't block the guest,
+/// or any other device.
+///
+/// Everything that uses the device's or queue's id holds a clone, as the ids are released once the last clone is gone.
+#[derive(Clone)]
+pub(crate) struct DevicePoller {
+    sender: mpsc::Sender<()>,
+}
+
+impl DevicePoller {
+    pub(crate) fn new(
+        instance: Arc<wgpu_core::global::Global>,
+        adapter: AdapterHandle,
+        device_id: wgpu_core::id::DeviceId,
+        queue_id: wgpu_core::id::QueueId,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel::<()>();
+        // Exits once the device and everything holding a clone of the poller are gone.
+        thread::spawn(move || {
+            while receiver.recv().is_ok() {
+                // A single wait covers every request made before it starts.
+                while receiver.try_recv().is_ok() {}
+                // Failures reach the guest through the callbacks wgpu-core fires, not from here.
+                let _ =
+                    instance.device_poll::<crate::Backend>(device_id, wgpu_types::Maintain::Wait);
+            }
+            // Nothing can reach the ids anymore, wgpu-core keeps the device alive for its remaining children.
+            instance.queue_drop::<crate::Backend>(queue_id);
+            instance.device_drop::<crate::Backend>(device_id);
+            // wgpu-core ignores dropping an adapter while a device requested from it is alive.
+            drop(adapter);
+        });
+        Self { sender }
+    }
+
+    /// Waits for the device's submitted work in the background, firing any callbacks that become ready.
+    pub(crate) fn poll(&self) {
+        let _ = self.sender.send(());
+    }
+}
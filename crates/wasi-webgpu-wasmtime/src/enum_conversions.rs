@@ -1,84 +1,192 @@
+use crate::error::UnsupportedFormat;
 use crate::wasi::webgpu::webgpu;
 
-impl From<wgpu_types::TextureFormat> for webgpu::GpuTextureFormat {
-    fn from(value: wgpu_types::TextureFormat) -> Self {
-        match value {
+impl TryFrom<wgpu_types::TextureFormat> for webgpu::GpuTextureFormat {
+    type Error = UnsupportedFormat<wgpu_types::TextureFormat>;
+
+    fn try_from(value: wgpu_types::TextureFormat) -> Result<Self, Self::Error> {
+        Ok(match value {
             wgpu_types::TextureFormat::Bgra8UnormSrgb => webgpu::GpuTextureFormat::Bgra8unormSrgb,
-            wgpu_types::TextureFormat::R8Unorm => todo!(),
-            wgpu_types::TextureFormat::R8Snorm => todo!(),
-            wgpu_types::TextureFormat::R8Uint => todo!(),
-            wgpu_types::TextureFormat::R8Sint => todo!(),
-            wgpu_types::TextureFormat::R16Uint => todo!(),
-            wgpu_types::TextureFormat::R16Sint => todo!(),
-            wgpu_types::TextureFormat::R16Unorm => todo!(),
-            wgpu_types::TextureFormat::R16Snorm => todo!(),
-            wgpu_types::TextureFormat::R16Float => todo!(),
-            wgpu_types::TextureFormat::Rg8Unorm => todo!(),
-            wgpu_types::TextureFormat::Rg8Snorm => todo!(),
-            wgpu_types::TextureFormat::Rg8Uint => todo!(),
-            wgpu_types::TextureFormat::Rg8Sint => todo!(),
-            wgpu_types::TextureFormat::R32Uint => todo!(),
-            wgpu_types::TextureFormat::R32Sint => todo!(),
-            wgpu_types::TextureFormat::R32Float => todo!(),
-            wgpu_types::TextureFormat::Rg16Uint => todo!(),
-            wgpu_types::TextureFormat::Rg16Sint => todo!(),
-            wgpu_types::TextureFormat::Rg16Unorm => todo!(),
-            wgpu_types::TextureFormat::Rg16Snorm => todo!(),
-            wgpu_types::TextureFormat::Rg16Float => todo!(),
-            wgpu_types::TextureFormat::Rgba8Unorm => todo!(),
-            wgpu_types::TextureFormat::Rgba8UnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Rgba8Snorm => todo!(),
-            wgpu_types::TextureFormat::Rgba8Uint => todo!(),
-            wgpu_types::TextureFormat::Rgba8Sint => todo!(),
-            wgpu_types::TextureFormat::Bgra8Unorm => todo!(),
-            wgpu_types::TextureFormat::Rgb9e5Ufloat => todo!(),
-            wgpu_types::TextureFormat::Rgb10a2Uint => todo!(),
-            wgpu_types::TextureFormat::Rgb10a2Unorm => todo!(),
-            wgpu_types::TextureFormat::Rg11b10Float => todo!(),
-            wgpu_types::TextureFormat::Rg32Uint => todo!(),
-            wgpu_types::TextureFormat::Rg32Sint => todo!(),
-            wgpu_types::TextureFormat::Rg32Float => todo!(),
-            wgpu_types::TextureFormat::Rgba16Uint => todo!(),
-            wgpu_types::TextureFormat::Rgba16Sint => todo!(),
-            wgpu_types::TextureFormat::Rgba16Unorm => todo!(),
-            wgpu_types::TextureFormat::Rgba16Snorm => todo!(),
-            wgpu_types::TextureFormat::Rgba16Float => todo!(),
-            wgpu_types::TextureFormat::Rgba32Uint => todo!(),
-            wgpu_types::TextureFormat::Rgba32Sint => todo!(),
-            wgpu_types::TextureFormat::Rgba32Float => todo!(),
-            wgpu_types::TextureFormat::Stencil8 => todo!(),
-            wgpu_types::TextureFormat::Depth16Unorm => todo!(),
-            wgpu_types::TextureFormat::Depth24Plus => todo!(),
-            wgpu_types::TextureFormat::Depth24PlusStencil8 => todo!(),
-            wgpu_types::TextureFormat::Depth32Float => todo!(),
-            wgpu_types::TextureFormat::Depth32FloatStencil8 => todo!(),
-            wgpu_types::TextureFormat::Bc1RgbaUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc1RgbaUnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Bc2RgbaUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc2RgbaUnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Bc3RgbaUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc3RgbaUnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Bc4RUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc4RSnorm => todo!(),
-            wgpu_types::TextureFormat::Bc5RgUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc5RgSnorm => todo!(),
-            wgpu_types::TextureFormat::Bc6hRgbUfloat => todo!(),
-            wgpu_types::TextureFormat::Bc6hRgbFloat => todo!(),
-            wgpu_types::TextureFormat::Bc7RgbaUnorm => todo!(),
-            wgpu_types::TextureFormat::Bc7RgbaUnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgb8Unorm => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgb8UnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgb8A1Unorm => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgb8A1UnormSrgb => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgba8Unorm => todo!(),
-            wgpu_types::TextureFormat::Etc2Rgba8UnormSrgb => todo!(),
-            wgpu_types::TextureFormat::EacR11Unorm => todo!(),
-            wgpu_types::TextureFormat::EacR11Snorm => todo!(),
-            wgpu_types::TextureFormat::EacRg11Unorm => todo!(),
-            wgpu_types::TextureFormat::EacRg11Snorm => todo!(),
-            wgpu_types::TextureFormat::Astc { .. } => todo!(),
-            wgpu_types::TextureFormat::NV12 => todo!(),
-        }
+            wgpu_types::TextureFormat::R8Unorm => webgpu::GpuTextureFormat::R8unorm,
+            wgpu_types::TextureFormat::R8Snorm => webgpu::GpuTextureFormat::R8snorm,
+            wgpu_types::TextureFormat::R8Uint => webgpu::GpuTextureFormat::R8uint,
+            wgpu_types::TextureFormat::R8Sint => webgpu::GpuTextureFormat::R8sint,
+            wgpu_types::TextureFormat::R16Uint => webgpu::GpuTextureFormat::R16uint,
+            wgpu_types::TextureFormat::R16Sint => webgpu::GpuTextureFormat::R16sint,
+            wgpu_types::TextureFormat::R16Float => webgpu::GpuTextureFormat::R16float,
+            wgpu_types::TextureFormat::Rg8Unorm => webgpu::GpuTextureFormat::Rg8unorm,
+            wgpu_types::TextureFormat::Rg8Snorm => webgpu::GpuTextureFormat::Rg8snorm,
+            wgpu_types::TextureFormat::Rg8Uint => webgpu::GpuTextureFormat::Rg8uint,
+            wgpu_types::TextureFormat::Rg8Sint => webgpu::GpuTextureFormat::Rg8sint,
+            wgpu_types::TextureFormat::R32Uint => webgpu::GpuTextureFormat::R32uint,
+            wgpu_types::TextureFormat::R32Sint => webgpu::GpuTextureFormat::R32sint,
+            wgpu_types::TextureFormat::R32Float => webgpu::GpuTextureFormat::R32float,
+            wgpu_types::TextureFormat::Rg16Uint => webgpu::GpuTextureFormat::Rg16uint,
+            wgpu_types::TextureFormat::Rg16Sint => webgpu::GpuTextureFormat::Rg16sint,
+            wgpu_types::TextureFormat::Rg16Float => webgpu::GpuTextureFormat::Rg16float,
+            wgpu_types::TextureFormat::Rgba8Unorm => webgpu::GpuTextureFormat::Rgba8unorm,
+            wgpu_types::TextureFormat::Rgba8UnormSrgb => webgpu::GpuTextureFormat::Rgba8unormSrgb,
+            wgpu_types::TextureFormat::Rgba8Snorm => webgpu::GpuTextureFormat::Rgba8snorm,
+            wgpu_types::TextureFormat::Rgba8Uint => webgpu::GpuTextureFormat::Rgba8uint,
+            wgpu_types::TextureFormat::Rgba8Sint => webgpu::GpuTextureFormat::Rgba8sint,
+            wgpu_types::TextureFormat::Bgra8Unorm => webgpu::GpuTextureFormat::Bgra8unorm,
+            wgpu_types::TextureFormat::Rgb9e5Ufloat => webgpu::GpuTextureFormat::Rgb9e5ufloat,
+            wgpu_types::TextureFormat::Rgb10a2Uint => webgpu::GpuTextureFormat::Rgb10a2uint,
+            wgpu_types::TextureFormat::Rgb10a2Unorm => webgpu::GpuTextureFormat::Rgb10a2unorm,
+            wgpu_types::TextureFormat::Rg11b10Float => webgpu::GpuTextureFormat::Rg11b10ufloat,
+            wgpu_types::TextureFormat::Rg32Uint => webgpu::GpuTextureFormat::Rg32uint,
+            wgpu_types::TextureFormat::Rg32Sint => webgpu::GpuTextureFormat::Rg32sint,
+            wgpu_types::TextureFormat::Rg32Float => webgpu::GpuTextureFormat::Rg32float,
+            wgpu_types::TextureFormat::Rgba16Uint => webgpu::GpuTextureFormat::Rgba16uint,
+            wgpu_types::TextureFormat::Rgba16Sint => webgpu::GpuTextureFormat::Rgba16sint,
+            wgpu_types::TextureFormat::Rgba16Float => webgpu::GpuTextureFormat::Rgba16float,
+            wgpu_types::TextureFormat::Rgba32Uint => webgpu::GpuTextureFormat::Rgba32uint,
+            wgpu_types::TextureFormat::Rgba32Sint => webgpu::GpuTextureFormat::Rgba32sint,
+            wgpu_types::TextureFormat::Rgba32Float => webgpu::GpuTextureFormat::Rgba32float,
+            wgpu_types::TextureFormat::Stencil8 => webgpu::GpuTextureFormat::Stencil8,
+            wgpu_types::TextureFormat::Depth16Unorm => webgpu::GpuTextureFormat::Depth16unorm,
+            wgpu_types::TextureFormat::Depth24Plus => webgpu::GpuTextureFormat::Depth24plus,
+            wgpu_types::TextureFormat::Depth24PlusStencil8 => {
+                webgpu::GpuTextureFormat::Depth24plusStencil8
+            }
+            wgpu_types::TextureFormat::Depth32Float => webgpu::GpuTextureFormat::Depth32float,
+            wgpu_types::TextureFormat::Depth32FloatStencil8 => {
+                webgpu::GpuTextureFormat::Depth32floatStencil8
+            }
+            wgpu_types::TextureFormat::Bc1RgbaUnorm => webgpu::GpuTextureFormat::Bc1RgbaUnorm,
+            wgpu_types::TextureFormat::Bc1RgbaUnormSrgb => {
+                webgpu::GpuTextureFormat::Bc1RgbaUnormSrgb
+            }
+            wgpu_types::TextureFormat::Bc2RgbaUnorm => webgpu::GpuTextureFormat::Bc2RgbaUnorm,
+            wgpu_types::TextureFormat::Bc2RgbaUnormSrgb => {
+                webgpu::GpuTextureFormat::Bc2RgbaUnormSrgb
+            }
+            wgpu_types::TextureFormat::Bc3RgbaUnorm => webgpu::GpuTextureFormat::Bc3RgbaUnorm,
+            wgpu_types::TextureFormat::Bc3RgbaUnormSrgb => {
+                webgpu::GpuTextureFormat::Bc3RgbaUnormSrgb
+            }
+            wgpu_types::TextureFormat::Bc4RUnorm => webgpu::GpuTextureFormat::Bc4RUnorm,
+            wgpu_types::TextureFormat::Bc4RSnorm => webgpu::GpuTextureFormat::Bc4RSnorm,
+            wgpu_types::TextureFormat::Bc5RgUnorm => webgpu::GpuTextureFormat::Bc5RgUnorm,
+            wgpu_types::TextureFormat::Bc5RgSnorm => webgpu::GpuTextureFormat::Bc5RgSnorm,
+            wgpu_types::TextureFormat::Bc6hRgbUfloat => webgpu::GpuTextureFormat::Bc6hRgbUfloat,
+            wgpu_types::TextureFormat::Bc6hRgbFloat => webgpu::GpuTextureFormat::Bc6hRgbFloat,
+            wgpu_types::TextureFormat::Bc7RgbaUnorm => webgpu::GpuTextureFormat::Bc7RgbaUnorm,
+            wgpu_types::TextureFormat::Bc7RgbaUnormSrgb => {
+                webgpu::GpuTextureFormat::Bc7RgbaUnormSrgb
+            }
+            wgpu_types::TextureFormat::Etc2Rgb8Unorm => webgpu::GpuTextureFormat::Etc2Rgb8unorm,
+            wgpu_types::TextureFormat::Etc2Rgb8UnormSrgb => {
+                webgpu::GpuTextureFormat::Etc2Rgb8unormSrgb
+            }
+            wgpu_types::TextureFormat::Etc2Rgb8A1Unorm => webgpu::GpuTextureFormat::Etc2Rgb8a1unorm,
+            wgpu_types::TextureFormat::Etc2Rgb8A1UnormSrgb => {
+                webgpu::GpuTextureFormat::Etc2Rgb8a1unormSrgb
+            }
+            wgpu_types::TextureFormat::Etc2Rgba8Unorm => webgpu::GpuTextureFormat::Etc2Rgba8unorm,
+            wgpu_types::TextureFormat::Etc2Rgba8UnormSrgb => {
+                webgpu::GpuTextureFormat::Etc2Rgba8unormSrgb
+            }
+            wgpu_types::TextureFormat::EacR11Unorm => webgpu::GpuTextureFormat::EacR11unorm,
+            wgpu_types::TextureFormat::EacR11Snorm => webgpu::GpuTextureFormat::EacR11snorm,
+            wgpu_types::TextureFormat::EacRg11Unorm => webgpu::GpuTextureFormat::EacRg11unorm,
+            wgpu_types::TextureFormat::EacRg11Snorm => webgpu::GpuTextureFormat::EacRg11snorm,
+            wgpu_types::TextureFormat::Astc { block, channel } => match (block, channel) {
+                (wgpu_types::AstcBlock::B4x4, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc4x4Unorm
+                }
+                (wgpu_types::AstcBlock::B4x4, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc4x4UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B5x4, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc5x4Unorm
+                }
+                (wgpu_types::AstcBlock::B5x4, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc5x4UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B5x5, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc5x5Unorm
+                }
+                (wgpu_types::AstcBlock::B5x5, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc5x5UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B6x5, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc6x5Unorm
+                }
+                (wgpu_types::AstcBlock::B6x5, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc6x5UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B6x6, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc6x6Unorm
+                }
+                (wgpu_types::AstcBlock::B6x6, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc6x6UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B8x5, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc8x5Unorm
+                }
+                (wgpu_types::AstcBlock::B8x5, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc8x5UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B8x6, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc8x6Unorm
+                }
+                (wgpu_types::AstcBlock::B8x6, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc8x6UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B8x8, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc8x8Unorm
+                }
+                (wgpu_types::AstcBlock::B8x8, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc8x8UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B10x5, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc10x5Unorm
+                }
+                (wgpu_types::AstcBlock::B10x5, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc10x5UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B10x6, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc10x6Unorm
+                }
+                (wgpu_types::AstcBlock::B10x6, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc10x6UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B10x8, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc10x8Unorm
+                }
+                (wgpu_types::AstcBlock::B10x8, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc10x8UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B10x10, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc10x10Unorm
+                }
+                (wgpu_types::AstcBlock::B10x10, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc10x10UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B12x10, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc12x10Unorm
+                }
+                (wgpu_types::AstcBlock::B12x10, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc12x10UnormSrgb
+                }
+                (wgpu_types::AstcBlock::B12x12, wgpu_types::AstcChannel::Unorm) => {
+                    webgpu::GpuTextureFormat::Astc12x12Unorm
+                }
+                (wgpu_types::AstcBlock::B12x12, wgpu_types::AstcChannel::UnormSrgb) => {
+                    webgpu::GpuTextureFormat::Astc12x12UnormSrgb
+                }
+                (_, wgpu_types::AstcChannel::Hdr) => return Err(UnsupportedFormat(value)),
+            },
+            // Native only formats.
+            wgpu_types::TextureFormat::R16Unorm
+            | wgpu_types::TextureFormat::R16Snorm
+            | wgpu_types::TextureFormat::Rg16Unorm
+            | wgpu_types::TextureFormat::Rg16Snorm
+            | wgpu_types::TextureFormat::Rgba16Unorm
+            | wgpu_types::TextureFormat::Rgba16Snorm
+            | wgpu_types::TextureFormat::NV12 => return Err(UnsupportedFormat(value)),
+        })
     }
 }
 
@@ -170,34 +278,118 @@ impl From<webgpu::GpuTextureFormat> for wgpu_types::TextureFormat {
             webgpu::GpuTextureFormat::EacR11snorm => wgpu_types::TextureFormat::EacR11Snorm,
             webgpu::GpuTextureFormat::EacRg11unorm => wgpu_types::TextureFormat::EacRg11Unorm,
             webgpu::GpuTextureFormat::EacRg11snorm => wgpu_types::TextureFormat::EacRg11Snorm,
-            webgpu::GpuTextureFormat::Astc4x4Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc4x4UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc5x4Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc5x4UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc5x5Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc5x5UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc6x5Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc6x5UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc6x6Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc6x6UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc8x5Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc8x5UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc8x6Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc8x6UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc8x8Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc8x8UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc10x5Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc10x5UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc10x6Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc10x6UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc10x8Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc10x8UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc10x10Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc10x10UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc12x10Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc12x10UnormSrgb => todo!(),
-            webgpu::GpuTextureFormat::Astc12x12Unorm => todo!(),
-            webgpu::GpuTextureFormat::Astc12x12UnormSrgb => todo!(),
+            webgpu::GpuTextureFormat::Astc4x4Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B4x4,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc4x4UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B4x4,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc5x4Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B5x4,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc5x4UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B5x4,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc5x5Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B5x5,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc5x5UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B5x5,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc6x5Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B6x5,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc6x5UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B6x5,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc6x6Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B6x6,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc6x6UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B6x6,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc8x5Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x5,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc8x5UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x5,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc8x6Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x6,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc8x6UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x6,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc8x8Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x8,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc8x8UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B8x8,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc10x5Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x5,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc10x5UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x5,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc10x6Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x6,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc10x6UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x6,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc10x8Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x8,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc10x8UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x8,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc10x10Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x10,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc10x10UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B10x10,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc12x10Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B12x10,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc12x10UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B12x10,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
+            webgpu::GpuTextureFormat::Astc12x12Unorm => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B12x12,
+                channel: wgpu_types::AstcChannel::Unorm,
+            },
+            webgpu::GpuTextureFormat::Astc12x12UnormSrgb => wgpu_types::TextureFormat::Astc {
+                block: wgpu_types::AstcBlock::B12x12,
+                channel: wgpu_types::AstcChannel::UnormSrgb,
+            },
         }
     }
 }
@@ -244,6 +436,37 @@ impl From<webgpu::GpuTextureDimension> for wgpu_types::TextureDimension {
     }
 }
 
+impl From<wgpu_types::TextureDimension> for webgpu::GpuTextureDimension {
+    fn from(value: wgpu_types::TextureDimension) -> Self {
+        match value {
+            wgpu_types::TextureDimension::D1 => webgpu::GpuTextureDimension::OneD,
+            wgpu_types::TextureDimension::D2 => webgpu::GpuTextureDimension::TwoD,
+            wgpu_types::TextureDimension::D3 => webgpu::GpuTextureDimension::ThreeD,
+        }
+    }
+}
+
+impl From<webgpu::GpuGlslShaderStage> for wgpu_core::naga::ShaderStage {
+    fn from(value: webgpu::GpuGlslShaderStage) -> Self {
+        match value {
+            webgpu::GpuGlslShaderStage::Vertex => wgpu_core::naga::ShaderStage::Vertex,
+            webgpu::GpuGlslShaderStage::Fragment => wgpu_core::naga::ShaderStage::Fragment,
+            webgpu::GpuGlslShaderStage::Compute => wgpu_core::naga::ShaderStage::Compute,
+        }
+    }
+}
+
+impl From<webgpu::GpuPowerPreference> for wgpu_types::PowerPreference {
+    fn from(value: webgpu::GpuPowerPreference) -> Self {
+        match value {
+            webgpu::GpuPowerPreference::LowPower => wgpu_types::PowerPreference::LowPower,
+            webgpu::GpuPowerPreference::HighPerformance => {
+                wgpu_types::PowerPreference::HighPerformance
+            }
+        }
+    }
+}
+
 impl From<webgpu::GpuAddressMode> for wgpu_types::AddressMode {
     fn from(value: webgpu::GpuAddressMode) -> Self {
         match value {
@@ -315,6 +538,20 @@ impl From<webgpu::GpuTextureSampleType> for wgpu_types::TextureSampleType {
     }
 }
 
+impl From<webgpu::GpuStorageTextureAccess> for wgpu_types::StorageTextureAccess {
+    fn from(value: webgpu::GpuStorageTextureAccess) -> Self {
+        match value {
+            webgpu::GpuStorageTextureAccess::WriteOnly => {
+                wgpu_types::StorageTextureAccess::WriteOnly
+            }
+            webgpu::GpuStorageTextureAccess::ReadOnly => wgpu_types::StorageTextureAccess::ReadOnly,
+            webgpu::GpuStorageTextureAccess::ReadWrite => {
+                wgpu_types::StorageTextureAccess::ReadWrite
+            }
+        }
+    }
+}
+
 impl From<webgpu::GpuTextureViewDimension> for wgpu_types::TextureViewDimension {
     fn from(value: webgpu::GpuTextureViewDimension) -> Self {
         match value {
@@ -362,28 +599,12 @@ impl From<webgpu::GpuStoreOp> for wgpu_core::command::StoreOp {
     }
 }
 
-impl From<webgpu::GpuColor> for wgpu_types::Color {
-    fn from(value: webgpu::GpuColor) -> Self {
-        match value {
-            webgpu::GpuColorDictOrListF64::GpuColorDict(webgpu::GpuColorDict { r, g, b, a }) => {
-                wgpu_types::Color { r, g, b, a }
-            }
-            webgpu::GpuColorDictOrListF64::ListF64(list) => wgpu_types::Color {
-                r: list[0],
-                g: list[1],
-                b: list[2],
-                a: list[3],
-            },
-        }
-    }
-}
-
-impl From<webgpu::GpuCullMode> for wgpu_types::Face {
+impl From<webgpu::GpuCullMode> for Option<wgpu_types::Face> {
     fn from(value: webgpu::GpuCullMode) -> Self {
         match value {
-            webgpu::GpuCullMode::None => todo!(),
-            webgpu::GpuCullMode::Front => wgpu_types::Face::Front,
-            webgpu::GpuCullMode::Back => wgpu_types::Face::Back,
+            webgpu::GpuCullMode::None => None,
+            webgpu::GpuCullMode::Front => Some(wgpu_types::Face::Front),
+            webgpu::GpuCullMode::Back => Some(wgpu_types::Face::Back),
         }
     }
 }
@@ -448,7 +669,7 @@ impl From<webgpu::GpuVertexFormat> for wgpu_types::VertexFormat {
             webgpu::GpuVertexFormat::Sint32x2 => wgpu_types::VertexFormat::Sint32x2,
             webgpu::GpuVertexFormat::Sint32x3 => wgpu_types::VertexFormat::Sint32x3,
             webgpu::GpuVertexFormat::Sint32x4 => wgpu_types::VertexFormat::Sint32x4,
-            webgpu::GpuVertexFormat::Unorm1010102 => todo!(),
+            webgpu::GpuVertexFormat::Unorm1010102 => wgpu_types::VertexFormat::Unorm10_10_10_2,
         }
     }
 }
@@ -519,3 +740,97 @@ impl From<webgpu::GpuTextureAspect> for wgpu_types::TextureAspect {
         }
     }
 }
+
+impl From<wgpu_types::DeviceLostReason> for webgpu::GpuDeviceLostReason {
+    fn from(value: wgpu_types::DeviceLostReason) -> Self {
+        match value {
+            wgpu_types::DeviceLostReason::Destroyed => webgpu::GpuDeviceLostReason::Destroyed,
+            // The rest are wgpu specific, the spec only knows about destroyed.
+            wgpu_types::DeviceLostReason::Unknown
+            | wgpu_types::DeviceLostReason::Dropped
+            | wgpu_types::DeviceLostReason::ReplacedCallback
+            | wgpu_types::DeviceLostReason::DeviceInvalid => webgpu::GpuDeviceLostReason::Unknown,
+        }
+    }
+}
+
+impl From<webgpu::GpuQueryType> for wgpu_types::QueryType {
+    fn from(value: webgpu::GpuQueryType) -> Self {
+        match value {
+            webgpu::GpuQueryType::Occlusion => wgpu_types::QueryType::Occlusion,
+            webgpu::GpuQueryType::Timestamp => wgpu_types::QueryType::Timestamp,
+        }
+    }
+}
+
+/// The names `gpu-supported-features` uses for each feature.
+pub(crate) const FEATURE_NAMES: [(webgpu::GpuFeatureName, &str); 11] = [
+    (
+        webgpu::GpuFeatureName::DepthClipControl,
+        "depth-clip-control",
+    ),
+    (
+        webgpu::GpuFeatureName::Depth32floatStencil8,
+        "depth32float-stencil8",
+    ),
+    (
+        webgpu::GpuFeatureName::TextureCompressionBc,
+        "texture-compression-bc",
+    ),
+    (
+        webgpu::GpuFeatureName::TextureCompressionEtc2,
+        "texture-compression-etc2",
+    ),
+    (
+        webgpu::GpuFeatureName::TextureCompressionAstc,
+        "texture-compression-astc",
+    ),
+    (webgpu::GpuFeatureName::TimestampQuery, "timestamp-query"),
+    (
+        webgpu::GpuFeatureName::IndirectFirstInstance,
+        "indirect-first-instance",
+    ),
+    (webgpu::GpuFeatureName::ShaderF16, "shader-f16"),
+    (
+        webgpu::GpuFeatureName::Rg11b10ufloatRenderable,
+        "rg11b10ufloat-renderable",
+    ),
+    (
+        webgpu::GpuFeatureName::Bgra8unormStorage,
+        "bgra8unorm-storage",
+    ),
+    (
+        webgpu::GpuFeatureName::Float32Filterable,
+        "float32-filterable",
+    ),
+];
+
+impl From<webgpu::GpuFeatureName> for wgpu_types::Features {
+    fn from(value: webgpu::GpuFeatureName) -> Self {
+        match value {
+            webgpu::GpuFeatureName::DepthClipControl => wgpu_types::Features::DEPTH_CLIP_CONTROL,
+            webgpu::GpuFeatureName::Depth32floatStencil8 => {
+                wgpu_types::Features::DEPTH32FLOAT_STENCIL8
+            }
+            webgpu::GpuFeatureName::TextureCompressionBc => {
+                wgpu_types::Features::TEXTURE_COMPRESSION_BC
+            }
+            webgpu::GpuFeatureName::TextureCompressionEtc2 => {
+                wgpu_types::Features::TEXTURE_COMPRESSION_ETC2
+            }
+            webgpu::GpuFeatureName::TextureCompressionAstc => {
+                wgpu_types::Features::TEXTURE_COMPRESSION_ASTC
+            }
+            webgpu::GpuFeatureName::TimestampQuery => wgpu_types::Features::TIMESTAMP_QUERY,
+            webgpu::GpuFeatureName::IndirectFirstInstance => {
+                wgpu_types::Features::INDIRECT_FIRST_INSTANCE
+            }
+            webgpu::GpuFeatureName::ShaderF16 => wgpu_types::Features::SHADER_F16,
+            webgpu::GpuFeatureName::Rg11b10ufloatRenderable => {
+                wgpu_types::Features::RG11B10UFLOAT_RENDERABLE
+            }
+            webgpu::GpuFeatureName::Bgra8unormStorage => wgpu_types::Features::BGRA8UNORM_STORAGE,
+            webgpu::GpuFeatureName::Float32Filterable => wgpu_types::Features::FLOAT32_FILTERABLE,
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use async_broadcast::{Receiver, RecvError};
+
+use crate::wasi::webgpu::webgpu;
+
+/// An error produced by wgpu-core, classified the way error scopes expect.
+///
+/// Backs the `gpu-validation-error`, `gpu-out-of-memory-error` and `gpu-internal-error` resources.
+#[derive(Clone, Debug)]
+pub struct GpuErrorInfo {
+    pub(crate) kind: webgpu::GpuErrorFilter,
+    pub(crate) message: String,
+}
+
+impl GpuErrorInfo {
+    fn from_core(error: &(dyn Error + 'static)) -> Self {
+        // source: https://www.w3.org/TR/webgpu/#errors-and-debugging
+        // Anything that isn't a device failure is a validation error.
+        let mut kind = webgpu::GpuErrorFilter::Validation;
+        let mut message = error.to_string();
+        let mut source = Some(error);
+        while let Some(error) = source {
+            match device_error(error) {
+                Some(wgpu_core::device::DeviceError::OutOfMemory) => {
+                    kind = webgpu::GpuErrorFilter::OutOfMemory;
+                }
+                Some(wgpu_core::device::DeviceError::ResourceCreationFailed) => {
+                    kind = webgpu::GpuErrorFilter::Internal;
+                }
+                _ => {}
+            }
+            source = error.source();
+            if let Some(source) = source {
+                message.push_str(&format!("\nCaused by: {source}"));
+            }
+        }
+        Self { kind, message }
+    }
+
+    pub(crate) fn into_resource(
+        self,
+        table: &mut wasmtime_wasi::ResourceTable,
+    ) -> wasmtime::Result<webgpu::GpuError> {
+        Ok(match self.kind {
+            webgpu::GpuErrorFilter::Validation => {
+                webgpu::GpuError::GpuValidationError(table.push(self)?)
+            }
+            webgpu::GpuErrorFilter::OutOfMemory => {
+                webgpu::GpuError::GpuOutOfMemoryError(table.push(self)?)
+            }
+            webgpu::GpuErrorFilter::Internal => {
+                webgpu::GpuError::GpuInternalError(table.push(self)?)
+            }
+        })
+    }
+
+    pub(crate) fn from_resource(
+        error: webgpu::GpuError,
+        table: &mut wasmtime_wasi::ResourceTable,
+    ) -> wasmtime::Result<Self> {
+        Ok(match error {
+            webgpu::GpuError::GpuValidationError(error) => table.delete(error)?,
+            webgpu::GpuError::GpuOutOfMemoryError(error) => table.delete(error)?,
+            webgpu::GpuError::GpuInternalError(error) => table.delete(error)?,
+        })
+    }
+}
+
+/// The `DeviceError` that a wgpu-core error holds, if any.
+///
+/// wgpu-core wraps them with `#[error(transparent)]`, which hides them from `source()`,
+/// so every error that can hold one is checked by type.
+fn device_error<'a>(
+    error: &'a (dyn Error + 'static),
+) -> Option<&'a wgpu_core::device::DeviceError> {
+    macro_rules! device_variants {
+        ($($($error:ident)::+ => $variant:ident),* $(,)?) => {
+            $(
+                if let Some($($error)::+::$variant(device_error)) =
+                    error.downcast_ref::<$($error)::+>()
+                {
+                    return Some(device_error);
+                }
+            )*
+        };
+    }
+    if let Some(device_error) = error.downcast_ref::<wgpu_core::device::DeviceError>() {
+        return Some(device_error);
+    }
+    if let Some(wgpu_core::command::CopyError::Encoder(
+        wgpu_core::command::CommandEncoderError::Device(device_error),
+    )) = error.downcast_ref::<wgpu_core::command::CopyError>()
+    {
+        return Some(device_error);
+    }
+    device_variants!(
+        wgpu_core::binding_model::CreateBindGroupError => Device,
+        wgpu_core::binding_model::CreateBindGroupLayoutError => Device,
+        wgpu_core::binding_model::CreatePipelineLayoutError => Device,
+        wgpu_core::command::ClearError => Device,
+        wgpu_core::command::CommandEncoderError => Device,
+        wgpu_core::command::ComputePassErrorInner => Device,
+        wgpu_core::command::QueryError => Device,
+        wgpu_core::command::RenderPassErrorInner => Device,
+        wgpu_core::device::queue::QueueSubmitError => Queue,
+        wgpu_core::device::queue::QueueWriteError => Queue,
+        wgpu_core::device::WaitIdleError => Device,
+        wgpu_core::pipeline::CreateComputePipelineError => Device,
+        wgpu_core::pipeline::CreateRenderPipelineError => Device,
+        wgpu_core::pipeline::CreateShaderModuleError => Device,
+        wgpu_core::resource::BufferAccessError => Device,
+        wgpu_core::resource::CreateBufferError => Device,
+        wgpu_core::resource::CreateQuerySetError => Device,
+        wgpu_core::resource::CreateSamplerError => Device,
+        wgpu_core::resource::CreateTextureError => Device,
+    );
+    None
+}
+
+/// A validation error caught by the host, for checks wgpu-core has no error type for.
+#[derive(Debug)]
+pub(crate) struct ValidationError(pub(crate) String);
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// A format that only one of WebGPU and wgpu has.
+#[derive(Debug)]
+pub struct UnsupportedFormat<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Display for UnsupportedFormat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Format {:?} is not supported", self.0)
+    }
+}
+
+impl<T: fmt::Debug> Error for UnsupportedFormat<T> {}
+
+/// Traps the guest when it calls something the host doesn't implement yet.
+pub(crate) fn not_implemented(name: &str) -> wasmtime::Error {
+    wasmtime::Error::msg(format!("{name} is not implemented"))
+}
+
+/// Backs the `gpu-pipeline-error` resource.
+#[derive(Clone, Debug)]
+pub struct PipelineError {
+    pub(crate) message: String,
+    pub(crate) reason: webgpu::GpuPipelineErrorReason,
+}
+
+impl PipelineError {
+    pub(crate) fn from_core(error: &(dyn Error + 'static)) -> Self {
+        let error = GpuErrorInfo::from_core(error);
+        // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createrenderpipelineasync
+        // There's no out of memory reason, so it's reported as an internal error.
+        let reason = match error.kind {
+            webgpu::GpuErrorFilter::Validation => webgpu::GpuPipelineErrorReason::Validation,
+            webgpu::GpuErrorFilter::OutOfMemory | webgpu::GpuErrorFilter::Internal => {
+                webgpu::GpuPipelineErrorReason::Internal
+            }
+        };
+        Self {
+            message: error.message,
+            reason,
+        }
+    }
+}
+
+struct ErrorScope {
+    filter: webgpu::GpuErrorFilter,
+    // Only the first error that matches the filter is kept.
+    error: Option<GpuErrorInfo>,
+}
+
+/// Per-device error scope stack.
+///
+/// Every resource that can produce wgpu-core errors keeps a clone of its device's sink.
+/// See https://www.w3.org/TR/webgpu/#error-scopes
+#[derive(Clone)]
+pub struct ErrorSink {
+    scopes: Arc<Mutex<Vec<ErrorScope>>>,
+
+    uncaptured_sender: async_broadcast::Sender<GpuErrorInfo>,
+    // Stops sends failing while no listener is subscribed.
+    _uncaptured_receiver: async_broadcast::InactiveReceiver<GpuErrorInfo>,
+}
+
+impl ErrorSink {
+    pub(crate) fn new() -> Self {
+        let (mut uncaptured_sender, uncaptured_receiver) = async_broadcast::broadcast(5);
+        // Drop the oldest error rather than blocking the host when the guest isn't keeping up.
+        uncaptured_sender.set_overflow(true);
+        let uncaptured_receiver = uncaptured_receiver.deactivate();
+        Self {
+            scopes: Default::default(),
+            uncaptured_sender,
+            _uncaptured_receiver: uncaptured_receiver,
+        }
+    }
+
+    pub(crate) fn push_scope(&self, filter: webgpu::GpuErrorFilter) {
+        self.scopes.lock().unwrap().push(ErrorScope {
+            filter,
+            error: None,
+        });
+    }
+
+    /// Returns `None` if the scope stack is empty.
+    pub(crate) fn pop_scope(&self) -> Option<Option<GpuErrorInfo>> {
+        self.scopes.lock().unwrap().pop().map(|scope| scope.error)
+    }
+
+    pub(crate) fn uncaptured_receiver(&self) -> Receiver<GpuErrorInfo> {
+        self.uncaptured_sender.new_receiver()
+    }
+
+    pub(crate) fn handle_error(&self, error: impl Error + 'static) {
+        let error = GpuErrorInfo::from_core(&error);
+        let mut scopes = self.scopes.lock().unwrap();
+        match scopes
+            .iter_mut()
+            .rev()
+            .find(|scope| scope.filter == error.kind)
+        {
+            Some(scope) => {
+                if scope.error.is_none() {
+                    scope.error = Some(error);
+                }
+            }
+            None => {
+                // Nobody listening for uncaptured errors is fine, the error is just dropped.
+                let _ = self.uncaptured_sender.try_broadcast(error);
+            }
+        }
+    }
+
+    /// Reports the error half of a wgpu-core `(id, error)` pair.
+    ///
+    /// wgpu-core hands out an invalid id on failure, so the id is returned either way.
+    pub(crate) fn handle_core_result<I, E>(
+        &self,
+        (id, error): (wgpu_core::id::Id<I>, Option<E>),
+    ) -> wgpu_core::id::Id<I>
+    where
+        I: wgpu_core::id::Marker,
+        E: Error + 'static,
+    {
+        if let Some(error) = error {
+            self.handle_error(error);
+        }
+        id
+    }
+
+    pub(crate) fn handle_result<T, E>(&self, result: Result<T, E>) -> Option<T>
+    where
+        E: Error + 'static,
+    {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.handle_error(error);
+                None
+            }
+        }
+    }
+}
+
+pub struct UncapturedErrorEvent {
+    pub(crate) error: GpuErrorInfo,
+}
+
+#[derive(Debug)]
+pub struct UncapturedErrorListener {
+    pub(crate) receiver: Receiver<GpuErrorInfo>,
+    pub(crate) data: Mutex<Option<GpuErrorInfo>>,
+}
+
+#[async_trait::async_trait]
+impl wasmtime_wasi::Subscribe for UncapturedErrorListener {
+    async fn ready(&mut self) {
+        loop {
+            match self.receiver.recv().await {
+                Ok(error) => {
+                    *self.data.lock().unwrap() = Some(error);
+                    return;
+                }
+                // Older errors were dropped to make room, the next one is still worth reporting.
+                Err(RecvError::Overflowed(_)) => continue,
+                // Device and all its children are gone, so no more errors can arrive.
+                Err(RecvError::Closed) => std::future::pending().await,
+            }
+        }
+    }
+}
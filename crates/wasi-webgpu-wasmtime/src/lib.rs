@@ -1,19 +1,23 @@
 // TODO: in this file:
 // - Remove all calls to `Default::default()`. Instead, manually set them, and link to the spec stating what defaults should be used.
 // - Implement all todos.
-// - Remove all unwraps.
-// - Implement all the drop handlers.
 
 use callback_future::CallbackFuture;
 use core::slice;
 use futures::executor::block_on;
 use std::borrow::Cow;
-use std::sync::Arc;
+use std::ffi::CString;
+use std::sync::{Arc, Mutex, Weak};
 use std::{future::Future, mem};
 use wasmtime::component::Resource;
 use wasmtime_wasi::WasiView;
 use wgpu_core::id::SurfaceId;
 
+use crate::adapter::AdapterHandle;
+use crate::buffer_mapping::{DeviceBuffers, MapState};
+use crate::device_poller::DevicePoller;
+use crate::error::{ErrorSink, ValidationError};
+use crate::query_set::QuerySetHandle;
 use crate::wasi::webgpu::webgpu;
 use wasi_graphics_context_wasmtime::{DisplayApi, DrawApi, GraphicsContext, GraphicsContextBuffer};
 
@@ -46,32 +50,49 @@ wasmtime::component::bindgen!({
     async: {
         only_imports: [
             "[method]gpu-buffer.map-async",
+            "[method]gpu-device.create-compute-pipeline-async",
+            "[method]gpu-device.create-render-pipeline-async",
+            "[method]gpu-queue.on-submitted-work-done",
         ],
     },
+    trappable_imports: true,
     with: {
-        "wasi:webgpu/webgpu/gpu-adapter": wgpu_core::id::AdapterId,
+        "wasi:webgpu/webgpu/gpu-adapter": Adapter,
         "wasi:webgpu/webgpu/gpu-device": Device,
-        "wasi:webgpu/webgpu/gpu-queue": wgpu_core::id::QueueId,
-        "wasi:webgpu/webgpu/gpu-command-encoder": wgpu_core::id::CommandEncoderId,
-        "wasi:webgpu/webgpu/gpu-render-pass-encoder": wgpu_core::command::RenderPass,
-        "wasi:webgpu/webgpu/gpu-compute-pass-encoder": wgpu_core::command::ComputePass,
-        "wasi:webgpu/webgpu/gpu-shader-module": wgpu_core::id::ShaderModuleId,
-        "wasi:webgpu/webgpu/gpu-render-pipeline": wgpu_core::id::RenderPipelineId,
-        "wasi:webgpu/webgpu/gpu-command-buffer": wgpu_core::id::CommandBufferId,
+        "wasi:io": wasmtime_wasi::bindings::io,
+        "wasi:webgpu/webgpu/gpu-queue": Queue,
+        "wasi:webgpu/webgpu/gpu-command-encoder": CommandEncoder,
+        "wasi:webgpu/webgpu/gpu-render-pass-encoder": RenderPassEncoder,
+        "wasi:webgpu/webgpu/gpu-render-bundle-encoder": RenderBundleEncoder,
+        "wasi:webgpu/webgpu/gpu-render-bundle": RenderBundle,
+        "wasi:webgpu/webgpu/gpu-compute-pass-encoder": ComputePassEncoder,
+        "wasi:webgpu/webgpu/gpu-shader-module": ShaderModule,
+        "wasi:webgpu/webgpu/gpu-render-pipeline": RenderPipeline,
+        "wasi:webgpu/webgpu/gpu-command-buffer": CommandBuffer,
         // "wasi:webgpu/webgpu/gpu-buffer": wgpu_core::id::BufferId,
         "wasi:webgpu/webgpu/gpu-buffer": Buffer,
-        "wasi:webgpu/webgpu/remote-buffer": Buffer,
-        "wasi:webgpu/webgpu/gpu-pipeline-layout": wgpu_core::id::PipelineLayoutId,
-        "wasi:webgpu/webgpu/gpu-bind-group-layout": wgpu_core::id::BindGroupLayoutId,
-        "wasi:webgpu/webgpu/gpu-sampler": wgpu_core::id::SamplerId,
+        "wasi:webgpu/webgpu/remote-buffer": RemoteBuffer,
+        "wasi:webgpu/webgpu/gpu-pipeline-layout": PipelineLayout,
+        "wasi:webgpu/webgpu/gpu-bind-group-layout": BindGroupLayout,
+        "wasi:webgpu/webgpu/gpu-sampler": Sampler,
         "wasi:webgpu/webgpu/gpu-supported-features": wgpu_types::Features,
-        "wasi:webgpu/webgpu/gpu-texture": wgpu_core::id::TextureId,
-        "wasi:webgpu/webgpu/gpu-compute-pipeline": wgpu_core::id::ComputePipelineId,
-        "wasi:webgpu/webgpu/gpu-bind-group": wgpu_core::id::BindGroupId,
-        "wasi:webgpu/webgpu/gpu-texture-view": wgpu_core::id::TextureViewId,
+        "wasi:webgpu/webgpu/gpu-texture": Texture,
+        "wasi:webgpu/webgpu/gpu-compute-pipeline": ComputePipeline,
+        "wasi:webgpu/webgpu/gpu-bind-group": BindGroup,
+        "wasi:webgpu/webgpu/gpu-texture-view": TextureView,
         "wasi:webgpu/webgpu/gpu-adapter-info": wgpu_types::AdapterInfo,
-        "wasi:webgpu/webgpu/gpu-query-set": wgpu_core::id::QuerySetId,
+        "wasi:webgpu/webgpu/gpu-query-set": QuerySet,
         "wasi:webgpu/webgpu/gpu-supported-limits": wgpu_types::Limits,
+        "wasi:webgpu/webgpu/gpu-validation-error": error::GpuErrorInfo,
+        "wasi:webgpu/webgpu/gpu-out-of-memory-error": error::GpuErrorInfo,
+        "wasi:webgpu/webgpu/gpu-internal-error": error::GpuErrorInfo,
+        "wasi:webgpu/webgpu/gpu-pipeline-error": error::PipelineError,
+        "wasi:webgpu/webgpu/gpu-uncaptured-error-event": error::UncapturedErrorEvent,
+        "wasi:webgpu/webgpu/gpu-device-lost-info": device_lost::DeviceLostInfo,
+        "wasi:webgpu/webgpu/gpu-compilation-info": compilation_info::CompilationInfo,
+        "wasi:webgpu/webgpu/gpu-compilation-message": compilation_info::CompilationMessage,
+        "wasi:webgpu/webgpu/device-lost-listener": device_lost::DeviceLostListener,
+        "wasi:webgpu/webgpu/uncaptured-error-listener": error::UncapturedErrorListener,
         "wasi:webgpu/graphics-context": wasi_graphics_context_wasmtime,
     },
 });
@@ -141,50 +162,107 @@ pub trait MainThreadSpawner: Send + Sync + 'static {
 pub struct WebGpuSurface<GI, CS, I>
 where
     I: AsRef<wgpu_core::global::Global>,
-    GI: Fn() -> I,
-    CS: Fn(&(dyn DisplayApi + Send + Sync)) -> SurfaceId,
+    GI: Fn() -> Option<I>,
+    CS: Fn(&(dyn DisplayApi + Send + Sync)) -> wasmtime::Result<SurfaceId>,
 {
     get_instance: GI,
     create_surface: CS,
     device_id: wgpu_core::id::DeviceId,
     adapter_id: wgpu_core::id::AdapterId,
     surface_id: Option<wgpu_core::id::SurfaceId>,
+    surface_config: Option<wgpu_types::SurfaceConfiguration<Vec<wgpu_types::TextureFormat>>>,
+    // Only held so the device's id stays valid for as long as the surface uses it.
+    _poller: DevicePoller,
+    error_sink: ErrorSink,
+}
+
+impl<GI, CS, I> WebGpuSurface<GI, CS, I>
+where
+    I: AsRef<wgpu_core::global::Global>,
+    GI: Fn() -> Option<I>,
+    CS: Fn(&(dyn DisplayApi + Send + Sync)) -> wasmtime::Result<SurfaceId>,
+{
+    fn instance(&self) -> wasmtime::Result<I> {
+        (self.get_instance)().ok_or_else(|| wasmtime::Error::msg("The wgpu instance is gone"))
+    }
 }
 
 impl<GI, CS, I> DrawApi for WebGpuSurface<GI, CS, I>
 where
     I: AsRef<wgpu_core::global::Global>,
-    GI: Fn() -> I,
-    CS: Fn(&(dyn DisplayApi + Send + Sync)) -> SurfaceId,
+    GI: Fn() -> Option<I>,
+    CS: Fn(&(dyn DisplayApi + Send + Sync)) -> wasmtime::Result<SurfaceId>,
 {
     fn get_current_buffer(&mut self) -> wasmtime::Result<GraphicsContextBuffer> {
-        let texture: wgpu_core::id::TextureId = (self.get_instance)()
+        let (Some(surface_id), Some(config)) = (self.surface_id, self.surface_config.as_ref())
+        else {
+            return Err(wasmtime::Error::msg("The display isn't ready yet"));
+        };
+        let output = self
+            .instance()?
             .as_ref()
-            .surface_get_current_texture::<crate::Backend>(self.surface_id.unwrap(), None)
-            .unwrap()
-            .texture_id
-            .unwrap();
-        let buff = Box::new(texture);
+            .surface_get_current_texture::<crate::Backend>(surface_id, None)?;
+        let Some(texture) = output.texture_id else {
+            return Err(wasmtime::Error::msg(format!(
+                "Couldn't get the surface texture: {:?}",
+                output.status
+            )));
+        };
+        let buff = Box::new(Texture {
+            texture,
+            descriptor: wgpu_types::TextureDescriptor {
+                label: (),
+                size: wgpu_types::Extent3d {
+                    width: config.width,
+                    height: config.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu_types::TextureDimension::D2,
+                format: config.format,
+                usage: config.usage,
+                view_formats: config.view_formats.clone(),
+            },
+            owned_by_surface: true,
+            error_sink: self.error_sink.clone(),
+            label: String::new(),
+        });
         let buff: GraphicsContextBuffer = buff.into();
         Ok(buff)
     }
 
     fn present(&mut self) -> wasmtime::Result<()> {
-        (self.get_instance)()
+        let Some(surface_id) = self.surface_id else {
+            return Err(wasmtime::Error::msg("The display isn't ready yet"));
+        };
+        self.instance()?
             .as_ref()
-            .surface_present::<crate::Backend>(self.surface_id.unwrap())
-            .unwrap();
+            .surface_present::<crate::Backend>(surface_id)?;
         Ok(())
     }
 
-    fn display_api_ready(&mut self, display: &Box<dyn DisplayApi + Send + Sync>) {
-        let surface_id = (self.create_surface)(display.as_ref());
+    fn display_api_ready(
+        &mut self,
+        display: &Box<dyn DisplayApi + Send + Sync>,
+    ) -> wasmtime::Result<()> {
+        let surface_id = (self.create_surface)(display.as_ref())?;
 
-        let swapchain_capabilities = (self.get_instance)()
+        let swapchain_capabilities = self
+            .instance()?
             .as_ref()
-            .surface_get_capabilities::<crate::Backend>(surface_id, self.adapter_id)
-            .unwrap();
-        let swapchain_format = swapchain_capabilities.formats[0];
+            .surface_get_capabilities::<crate::Backend>(surface_id, self.adapter_id)?;
+        // The guest reads the format back through `gpu-texture.format`, so it needs a WebGPU equivalent.
+        let Some(swapchain_format) = swapchain_capabilities
+            .formats
+            .iter()
+            .copied()
+            .find(|format| webgpu::GpuTextureFormat::try_from(*format).is_ok())
+        else {
+            return Err(wasmtime::Error::msg(
+                "The surface doesn't support any WebGPU texture format",
+            ));
+        };
 
         let config = wgpu_types::SurfaceConfiguration {
             usage: wgpu_types::TextureUsages::RENDER_ATTACHMENT,
@@ -198,17 +276,31 @@ where
             desired_maximum_frame_latency: 2,
         };
 
-        (self.get_instance)()
+        if let Some(error) = self
+            .instance()?
             .as_ref()
-            .surface_configure::<crate::Backend>(surface_id, self.device_id, &config);
+            .surface_configure::<crate::Backend>(surface_id, self.device_id, &config)
+        {
+            return Err(error.into());
+        }
 
         self.surface_id = Some(surface_id);
+        self.surface_config = Some(config);
+        Ok(())
     }
 }
 
 // ToCore trait used for resources, records, and variants.
 // Into trait used for enums, since they never need table access.
+mod adapter;
+mod buffer_mapping;
+mod compilation_info;
+mod device_lost;
+mod device_poller;
 mod enum_conversions;
+mod error;
+mod limits;
+mod query_set;
 mod to_core_conversions;
 
 pub struct BufferPtr {
@@ -227,74 +319,294 @@ impl BufferPtr {
 unsafe impl Send for BufferPtr {}
 unsafe impl Sync for BufferPtr {}
 
+pub struct RemoteBuffer {
+    mapped: Option<BufferPtr>,
+    // Gone once the buffer is unmapped or destroyed, at which point `mapped` is dangling.
+    mapping: Weak<()>,
+    error_sink: ErrorSink,
+}
+impl RemoteBuffer {
+    fn len(&self) -> u64 {
+        match &self.mapped {
+            Some(mapped) if self.mapping.strong_count() > 0 => mapped.len,
+            // Like a detached `ArrayBuffer`.
+            _ => 0,
+        }
+    }
+
+    fn range(&self, offset: u64, len: u64) -> Option<std::ops::Range<usize>> {
+        if self.mapped.is_none() || self.mapping.strong_count() == 0 {
+            self.error_sink
+                .handle_error(wgpu_core::resource::BufferAccessError::NotMapped);
+            return None;
+        }
+        let end = offset + len;
+        if end > self.len() {
+            self.error_sink.handle_error(
+                wgpu_core::resource::BufferAccessError::OutOfBoundsOverrun {
+                    index: end,
+                    max: self.len(),
+                },
+            );
+            return None;
+        }
+        Some(offset as usize..end as usize)
+    }
+
+    /// Reports a validation error and returns `None` if the range isn't mapped.
+    fn slice(&self, offset: u64, len: u64) -> Option<&[u8]> {
+        let range = self.range(offset, len)?;
+        Some(&self.mapped.as_ref().unwrap().slice()[range])
+    }
+
+    /// Reports a validation error and returns `None` if the range isn't mapped.
+    fn slice_mut(&mut self, offset: u64, len: u64) -> Option<&mut [u8]> {
+        let range = self.range(offset, len)?;
+        Some(&mut self.mapped.as_mut().unwrap().slice_mut()[range])
+    }
+}
+
 pub struct Buffer {
     buffer: wgpu_core::id::BufferId,
-    mapped: Option<BufferPtr>,
+    size: u64,
+    usage: wgpu_types::BufferUsages,
+    // Shared with the device, which unmaps the buffer when destroyed.
+    map_state: Arc<Mutex<MapState>>,
+    poller: DevicePoller,
+    error_sink: ErrorSink,
+    label: String,
+}
+
+pub struct Adapter {
+    pub(crate) adapter: AdapterHandle,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct Device {
     pub device: wgpu_core::id::DeviceId,
     pub queue: wgpu_core::id::QueueId,
     // only needed when calling surface.get_capabilities in connect_graphics_context. If table would have a way to get parent from child, we could get it from device.
     pub adapter: wgpu_core::id::AdapterId,
+    // Children keep a clone of this, as the table has no way to get the parent device from a child.
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) lost: device_lost::DeviceLost,
+    pub(crate) poller: DevicePoller,
+    pub(crate) buffers: DeviceBuffers,
+    pub(crate) label: String,
+    // From `default-queue`, wgpu-core has no notion of a queue label.
+    pub(crate) queue_label: String,
+}
+
+// wgpu-core only uses labels for its own error messages and debugging tools, it has no way to read them back or change them.
+// So every resource keeps its own copy of the label for `label` and `set-label`.
+
+pub struct Queue {
+    pub(crate) queue: wgpu_core::id::QueueId,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) poller: DevicePoller,
+    pub(crate) label: String,
+}
+
+pub struct CommandEncoder {
+    pub(crate) command_encoder: wgpu_core::id::CommandEncoderId,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct CommandBuffer {
+    pub(crate) command_buffer: wgpu_core::id::CommandBufferId,
+    pub(crate) label: String,
+}
+
+pub struct Texture {
+    pub(crate) texture: wgpu_core::id::TextureId,
+    // Kept for the size, format and usage getters.
+    pub(crate) descriptor: wgpu_types::TextureDescriptor<(), Vec<wgpu_types::TextureFormat>>,
+    // Surface textures are released by wgpu-core once presented, not by dropping them.
+    pub(crate) owned_by_surface: bool,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct TextureView {
+    pub(crate) texture_view: wgpu_core::id::TextureViewId,
+    // The size a render pass drawing to this view has.
+    pub(crate) render_extent: wgpu_types::Extent3d,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct Sampler {
+    pub(crate) sampler: wgpu_core::id::SamplerId,
+    pub(crate) label: String,
+}
+
+pub struct ShaderModule {
+    pub(crate) shader_module: wgpu_core::id::ShaderModuleId,
+    pub(crate) compilation_messages: Vec<compilation_info::CompilationMessage>,
+    pub(crate) label: String,
+}
+
+pub struct BindGroupLayout {
+    pub(crate) bind_group_layout: wgpu_core::id::BindGroupLayoutId,
+    pub(crate) label: String,
+}
+
+pub struct BindGroup {
+    pub(crate) bind_group: wgpu_core::id::BindGroupId,
+    pub(crate) label: String,
+}
+
+pub struct PipelineLayout {
+    pub(crate) pipeline_layout: wgpu_core::id::PipelineLayoutId,
+    pub(crate) label: String,
+}
+
+pub struct RenderPipeline {
+    pub(crate) render_pipeline: wgpu_core::id::RenderPipelineId,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct RenderPassEncoder {
+    pub(crate) render_pass: wgpu_core::command::RenderPass,
+    // None if the pass has no attachments, which wgpu-core reports once it ends.
+    pub(crate) render_extent: Option<wgpu_types::Extent3d>,
+    // Keeps the ids of the pass's query sets valid until it ends.
+    pub(crate) _query_sets: Vec<QuerySetHandle>,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct RenderBundleEncoder {
+    pub(crate) render_bundle_encoder: wgpu_core::command::RenderBundleEncoder,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct RenderBundle {
+    pub(crate) render_bundle: wgpu_core::id::RenderBundleId,
+    pub(crate) label: String,
+}
+
+pub struct ComputePipeline {
+    pub(crate) compute_pipeline: wgpu_core::id::ComputePipelineId,
+    pub(crate) error_sink: ErrorSink,
+    pub(crate) label: String,
+}
+
+pub struct ComputePassEncoder {
+    pub(crate) compute_pass: wgpu_core::command::ComputePass,
+    // Keeps the ids of the pass's query sets valid until it ends.
+    pub(crate) _query_sets: Vec<QuerySetHandle>,
+    pub(crate) label: String,
+}
+
+pub struct QuerySet {
+    // None once destroyed.
+    pub(crate) query_set: Option<QuerySetHandle>,
+    // wgpu-core doesn't let us read these back either.
+    pub(crate) type_: webgpu::GpuQueryType,
+    pub(crate) count: u32,
+    pub(crate) label: String,
 }
 
 impl<T: WasiWebGpuView> webgpu::Host for WasiWebGpuImpl<T> {
-    fn get_gpu(&mut self) -> Resource<webgpu::Gpu> {
-        Resource::new_own(0)
+    fn get_gpu(&mut self) -> wasmtime::Result<Resource<webgpu::Gpu>> {
+        Ok(Resource::new_own(0))
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostRemoteBuffer for WasiWebGpuImpl<T> {
-    fn length(&mut self, buffer: Resource<webgpu::RemoteBuffer>) -> u32 {
-        let buffer = self.0.table().get(&buffer).unwrap();
-        let len = buffer.mapped.as_ref().unwrap().len;
-        len as u32
+    fn length(&mut self, buffer: Resource<webgpu::RemoteBuffer>) -> wasmtime::Result<u32> {
+        let buffer = self.0.table().get(&buffer)?;
+        Ok(buffer.len() as u32)
     }
 
-    fn get(&mut self, buffer: Resource<webgpu::RemoteBuffer>, i: u32) -> u8 {
-        let buffer = self.0.table().get(&buffer).unwrap();
-        let remote_buffer = buffer.mapped.as_ref().unwrap();
-        let val = remote_buffer.slice()[i as usize];
-        val
+    fn get(&mut self, buffer: Resource<webgpu::RemoteBuffer>, i: u32) -> wasmtime::Result<u8> {
+        let buffer = self.0.table().get(&buffer)?;
+        Ok(buffer
+            .slice(i as u64, 1)
+            .map(|slice| slice[0])
+            .unwrap_or_default())
     }
 
-    fn set(&mut self, buffer: Resource<webgpu::RemoteBuffer>, i: u32, val: u8) {
-        let buffer = self.0.table().get_mut(&buffer).unwrap();
-        let remote_buffer = buffer.mapped.as_mut().unwrap();
-        remote_buffer.slice_mut()[i as usize] = val;
+    fn set(
+        &mut self,
+        buffer: Resource<webgpu::RemoteBuffer>,
+        i: u32,
+        val: u8,
+    ) -> wasmtime::Result<()> {
+        let buffer = self.0.table().get_mut(&buffer)?;
+        if let Some(slice) = buffer.slice_mut(i as u64, 1) {
+            slice[0] = val;
+        }
+
+        Ok(())
+    }
+
+    fn read(
+        &mut self,
+        buffer: Resource<webgpu::RemoteBuffer>,
+        offset: u32,
+        length: u32,
+    ) -> wasmtime::Result<Vec<u8>> {
+        let buffer = self.0.table().get(&buffer)?;
+        Ok(buffer
+            .slice(offset as u64, length as u64)
+            .map(|slice| slice.to_vec())
+            .unwrap_or_default())
+    }
+
+    fn write(
+        &mut self,
+        buffer: Resource<webgpu::RemoteBuffer>,
+        offset: u32,
+        data: Vec<u8>,
+    ) -> wasmtime::Result<()> {
+        let buffer = self.0.table().get_mut(&buffer)?;
+        if let Some(slice) = buffer.slice_mut(offset as u64, data.len() as u64) {
+            slice.copy_from_slice(&data);
+        }
+
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::RemoteBuffer>) -> wasmtime::Result<()> {
+    fn drop(&mut self, buffer: Resource<webgpu::RemoteBuffer>) -> wasmtime::Result<()> {
+        self.0.table().delete(buffer)?;
         Ok(())
     }
 }
 
+#[async_trait::async_trait]
 impl<T: WasiWebGpuView> webgpu::HostGpuDevice for WasiWebGpuImpl<T> {
     fn connect_graphics_context(
         &mut self,
         device: Resource<Device>,
         context: Resource<GraphicsContext>,
-    ) {
-        let device = self.0.table().get(&device).unwrap();
+    ) -> wasmtime::Result<()> {
+        let device = self.0.table().get(&device)?;
         let device_id = device.device;
         let adapter_id = device.adapter;
+        let poller = device.poller.clone();
+        let error_sink = device.error_sink.clone();
 
         let instance = Arc::downgrade(&self.0.instance());
         let surface_creator = self.0.ui_thread_spawner();
 
-        let context = self.0.table().get_mut(&context).unwrap();
+        let context = self.0.table().get_mut(&context)?;
 
         let surface = WebGpuSurface {
             get_instance: {
                 let instance = instance.clone();
-                move || instance.upgrade().unwrap()
+                move || instance.upgrade()
             },
             create_surface: {
                 let instance = instance.clone();
                 move |display: &(dyn DisplayApi + Send + Sync)| {
-                    let instance = instance.upgrade().unwrap();
+                    let Some(instance) = instance.upgrade() else {
+                        return Err(wasmtime::Error::msg("The wgpu instance is gone"));
+                    };
 
                     // TODO: make spawn behave similar to `std::thread::scope` so that we don't have to unsafely transmute display to `&'static`.
                     // Something like the following:
@@ -316,80 +628,176 @@ impl<T: WasiWebGpuView> webgpu::HostGpuDevice for WasiWebGpuImpl<T> {
                     let display: &'static (dyn DisplayApi + Send + Sync) =
                         unsafe { mem::transmute(display) };
                     block_on(surface_creator.spawn(move || unsafe {
-                        instance
-                            .instance_create_surface(
-                                display.display_handle().unwrap().as_raw(),
-                                display.window_handle().unwrap().as_raw(),
-                                None,
-                            )
-                            .unwrap()
+                        Ok(instance.instance_create_surface(
+                            display.display_handle()?.as_raw(),
+                            display.window_handle()?.as_raw(),
+                            None,
+                        )?)
                     }))
                 }
             },
             device_id,
             adapter_id,
             surface_id: None,
+            surface_config: None,
+            _poller: poller,
+            error_sink,
         };
 
-        context.connect_draw_api(Box::new(surface));
+        context.connect_draw_api(Box::new(surface))
     }
 
     fn create_command_encoder(
         &mut self,
         device: Resource<Device>,
         descriptor: Option<webgpu::GpuCommandEncoderDescriptor>,
-    ) -> Resource<wgpu_core::id::CommandEncoderId> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<CommandEncoder>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        let command_encoder = core_result(
+        let label = descriptor
+            .as_ref()
+            .and_then(|d| d.label.clone())
+            .unwrap_or_default();
+        let command_encoder = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_command_encoder::<crate::Backend>(
-                    device,
+                    device_id,
                     &descriptor
-                        .map(|d| d.to_core(&self.0.table()))
+                        .map(|d| d.to_core(self.0.table()))
+                        .transpose()?
                         .unwrap_or_default(),
                     None,
                 ),
-        )
-        .unwrap();
+        );
 
-        self.0.table().push(command_encoder).unwrap()
+        Ok(self.0.table().push(CommandEncoder {
+            command_encoder,
+            error_sink,
+            label,
+        })?)
     }
 
     fn create_shader_module(
         &mut self,
         device: Resource<Device>,
         descriptor: webgpu::GpuShaderModuleDescriptor,
-    ) -> Resource<webgpu::GpuShaderModule> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuShaderModule>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        let code =
-            wgpu_core::pipeline::ShaderModuleSource::Wgsl(Cow::Owned(descriptor.code.to_owned()));
-        let shader = core_result(
-            self.0
-                .instance()
-                .device_create_shader_module::<crate::Backend>(
-                    device,
-                    &descriptor.to_core(&self.0.table()),
-                    code,
-                    None,
-                ),
-        )
-        .unwrap();
+        let label = descriptor.label.clone().unwrap_or_default();
+        let source = descriptor.code.clone();
+        let code = wgpu_core::pipeline::ShaderModuleSource::Wgsl(Cow::Borrowed(&source));
+        let (shader_module, error) = self
+            .0
+            .instance()
+            .device_create_shader_module::<crate::Backend>(
+                device_id,
+                &descriptor.to_core(self.0.table())?,
+                code,
+                None,
+            );
+        let compilation_info = compilation_info::CompilationInfo::new(&source, error.as_ref());
+        let shader_module = error_sink.handle_core_result((shader_module, error));
 
-        self.0.table().push(shader).unwrap()
+        Ok(self.0.table().push(ShaderModule {
+            shader_module,
+            compilation_messages: compilation_info.messages,
+            label,
+        })?)
+    }
+
+    fn create_shader_module_from_source(
+        &mut self,
+        device: Resource<Device>,
+        source: webgpu::GpuShaderModuleSource,
+        label: Option<String>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuShaderModule>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
+
+        // Compilation messages point into the source text, SPIR-V doesn't have any.
+        let source_text = match &source {
+            webgpu::GpuShaderModuleSource::Spirv(_) => String::new(),
+            webgpu::GpuShaderModuleSource::Glsl(glsl) => glsl.code.clone(),
+        };
+        let code = match source {
+            #[cfg(feature = "spirv")]
+            webgpu::GpuShaderModuleSource::Spirv(words) => {
+                wgpu_core::pipeline::ShaderModuleSource::SpirV(
+                    Cow::Owned(words),
+                    Default::default(),
+                )
+            }
+            #[cfg(feature = "glsl")]
+            webgpu::GpuShaderModuleSource::Glsl(glsl) => {
+                wgpu_core::pipeline::ShaderModuleSource::Glsl(
+                    Cow::Owned(glsl.code),
+                    wgpu_core::naga::front::glsl::Options::from(
+                        wgpu_core::naga::ShaderStage::from(glsl.stage),
+                    ),
+                )
+            }
+            #[allow(unreachable_patterns)]
+            source => {
+                let feature = match source {
+                    webgpu::GpuShaderModuleSource::Spirv(_) => "spirv",
+                    webgpu::GpuShaderModuleSource::Glsl(_) => "glsl",
+                };
+                error_sink.handle_error(ValidationError(format!(
+                    "Shader source language is not supported, the host needs the `{feature}` feature"
+                )));
+                // Still hand out a shader module, it just has no entry points to use.
+                wgpu_core::pipeline::ShaderModuleSource::Naga(Cow::Owned(Default::default()))
+            }
+        };
+        let (shader_module, error) = self
+            .0
+            .instance()
+            .device_create_shader_module::<crate::Backend>(
+                device_id,
+                &wgpu_core::pipeline::ShaderModuleDescriptor {
+                    label: label.clone().map(|l| l.into()),
+                    shader_bound_checks: wgpu_types::ShaderBoundChecks::new(),
+                },
+                code,
+                None,
+            );
+        let compilation_info = compilation_info::CompilationInfo::new(&source_text, error.as_ref());
+        let shader_module = error_sink.handle_core_result((shader_module, error));
+
+        Ok(self.0.table().push(ShaderModule {
+            shader_module,
+            compilation_messages: compilation_info.messages,
+            label: label.unwrap_or_default(),
+        })?)
     }
 
     fn create_render_pipeline(
         &mut self,
         device: Resource<Device>,
         descriptor: webgpu::GpuRenderPipelineDescriptor,
-    ) -> Resource<wgpu_core::id::RenderPipelineId> {
-        let host_device = self.0.table().get(&device).unwrap().device;
-
-        let descriptor = descriptor.to_core(&self.0.table());
+    ) -> wasmtime::Result<Resource<webgpu::GpuRenderPipeline>> {
+        let host_device = self.0.table().get(&device)?;
+        let device_id = host_device.device;
+        let error_sink = host_device.error_sink.clone();
+
+        let label = descriptor.label.clone().unwrap_or_default();
+        if let Some(Err(error)) = descriptor
+            .depth_stencil
+            .as_ref()
+            .map(to_core_conversions::check_depth_stencil_state)
+        {
+            error_sink.handle_error(error);
+        }
+        let descriptor = descriptor.to_core(self.0.table())?;
 
+        // wgpu-core picks the ids and keeps the implicit layouts with the pipeline, for `get-bind-group-layout`.
         let implicit_pipeline_ids = match descriptor.layout {
             Some(_) => None,
             None => Some(wgpu_core::device::ImplicitPipelineIds {
@@ -397,190 +805,271 @@ impl<T: WasiWebGpuView> webgpu::HostGpuDevice for WasiWebGpuImpl<T> {
                 group_ids: &[None; wgpu_core::MAX_BIND_GROUPS],
             }),
         };
-        let render_pipeline = core_result(
+        let render_pipeline = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_render_pipeline::<crate::Backend>(
-                    host_device,
+                    device_id,
                     &descriptor,
                     None,
                     implicit_pipeline_ids,
                 ),
-        )
-        .unwrap();
+        );
 
-        self.0.table().push_child(render_pipeline, &device).unwrap()
+        Ok(self.0.table().push(RenderPipeline {
+            render_pipeline,
+            error_sink,
+            label,
+        })?)
     }
 
-    fn queue(&mut self, device: Resource<Device>) -> Resource<wgpu_core::id::QueueId> {
-        let queue = self.0.table().get(&device).unwrap().queue;
-        self.0.table().push(queue).unwrap()
+    fn queue(&mut self, device: Resource<Device>) -> wasmtime::Result<Resource<Queue>> {
+        let device = self.0.table().get(&device)?;
+        let queue = Queue {
+            queue: device.queue,
+            error_sink: device.error_sink.clone(),
+            poller: device.poller.clone(),
+            label: device.queue_label.clone(),
+        };
+        Ok(self.0.table().push(queue)?)
     }
 
     fn features(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
-    ) -> Resource<webgpu::GpuSupportedFeatures> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuSupportedFeatures>> {
+        let device = self.0.table().get(&device)?.device;
         let features = self
             .0
             .instance()
-            .device_features::<crate::Backend>(device)
-            .unwrap();
-        self.0.table().push(features).unwrap()
+            .device_features::<crate::Backend>(device)?;
+        Ok(self.0.table().push(features)?)
     }
 
     fn limits(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
-    ) -> Resource<webgpu::GpuSupportedLimits> {
-        let device = self.0.table().get(&device).unwrap().device;
-        let limits = self
-            .0
-            .instance()
-            .device_limits::<crate::Backend>(device)
-            .unwrap();
-        self.0.table().push(limits).unwrap()
+    ) -> wasmtime::Result<Resource<webgpu::GpuSupportedLimits>> {
+        let device = self.0.table().get(&device)?.device;
+        let limits = self.0.instance().device_limits::<crate::Backend>(device)?;
+        Ok(self.0.table().push(limits)?)
     }
 
-    fn destroy(&mut self, _device: Resource<webgpu::GpuDevice>) {
-        todo!()
+    fn destroy(&mut self, device: Resource<webgpu::GpuDevice>) -> wasmtime::Result<()> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let buffers = device.buffers.clone();
+        // wgpu-core frees the memory of mapped buffers, so remote buffers have to be detached first.
+        buffers.unmap_all(&self.0.instance());
+        // Children are invalidated by wgpu-core, using them from now on results in validation errors.
+        self.0
+            .instance()
+            .device_destroy::<crate::Backend>(device_id);
+        // wgpu-core only releases the resources and resolves `lost` on the next maintain.
+        // Polling a destroyed device can fail, which isn't an error the guest caused.
+        let _ = self
+            .0
+            .instance()
+            .device_poll::<crate::Backend>(device_id, wgpu_types::Maintain::Wait);
+        Ok(())
     }
 
     fn create_buffer(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuBufferDescriptor,
-    ) -> Resource<webgpu::GpuBuffer> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuBuffer>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let poller = device.poller.clone();
+        let buffers = device.buffers.clone();
+        let error_sink = device.error_sink.clone();
 
-        let buffer = core_result(self.0.instance().device_create_buffer::<crate::Backend>(
-            device,
-            &descriptor.to_core(&self.0.table()),
-            None,
-        ))
-        .unwrap();
+        let label = descriptor.label.clone().unwrap_or_default();
+        let descriptor = descriptor.to_core(self.0.table())?;
+        let buffer = error_sink.handle_core_result(
+            self.0
+                .instance()
+                .device_create_buffer::<crate::Backend>(device_id, &descriptor, None),
+        );
 
+        let map_state = MapState::new(descriptor.mapped_at_creation);
+        buffers.track(buffer, &map_state);
         let buffer = Buffer {
             buffer,
-            mapped: None,
+            size: descriptor.size,
+            usage: descriptor.usage,
+            map_state,
+            poller,
+            error_sink,
+            label,
         };
 
-        self.0.table().push(buffer).unwrap()
+        Ok(self.0.table().push(buffer)?)
     }
 
     fn create_texture(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuTextureDescriptor,
-    ) -> Resource<webgpu::GpuTexture> {
-        let device = self.0.table().get(&device).unwrap().device;
-        let texture = core_result(self.0.instance().device_create_texture::<crate::Backend>(
-            device,
-            &descriptor.to_core(&self.0.table()),
-            None,
-        ))
-        .unwrap();
+    ) -> wasmtime::Result<Resource<webgpu::GpuTexture>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        self.0.table().push(texture).unwrap()
+        let label = descriptor.label.clone().unwrap_or_default();
+        let descriptor = descriptor.to_core(self.0.table())?;
+        let texture = error_sink.handle_core_result(
+            self.0
+                .instance()
+                .device_create_texture::<crate::Backend>(device_id, &descriptor, None),
+        );
+
+        Ok(self.0.table().push(Texture {
+            texture,
+            descriptor: descriptor.map_label(|_| ()),
+            owned_by_surface: false,
+            error_sink,
+            label,
+        })?)
     }
 
     fn create_sampler(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: Option<webgpu::GpuSamplerDescriptor>,
-    ) -> Resource<webgpu::GpuSampler> {
-        let device = self.0.table().get(&device).unwrap().device;
-
-        let descriptor = descriptor.unwrap();
-
-        let sampler = core_result(self.0.instance().device_create_sampler::<crate::Backend>(
-            device,
-            &descriptor.to_core(&self.0.table()),
-            None,
-        ))
-        .unwrap();
+    ) -> wasmtime::Result<Resource<webgpu::GpuSampler>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
+
+        // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createsampler
+        // Leaving out the descriptor is the same as leaving out all of its members.
+        let descriptor = descriptor.unwrap_or(webgpu::GpuSamplerDescriptor {
+            address_mode_u: None,
+            address_mode_v: None,
+            address_mode_w: None,
+            mag_filter: None,
+            min_filter: None,
+            mipmap_filter: None,
+            lod_min_clamp: None,
+            lod_max_clamp: None,
+            compare: None,
+            max_anisotropy: None,
+            label: None,
+        });
+        let label = descriptor.label.clone().unwrap_or_default();
+
+        let sampler = error_sink.handle_core_result(
+            self.0.instance().device_create_sampler::<crate::Backend>(
+                device_id,
+                &descriptor.to_core(self.0.table())?,
+                None,
+            ),
+        );
 
-        self.0.table().push(sampler).unwrap()
+        Ok(self.0.table().push(Sampler { sampler, label })?)
     }
 
     fn import_external_texture(
         &mut self,
         _device: Resource<webgpu::GpuDevice>,
         _descriptor: webgpu::GpuExternalTextureDescriptor,
-    ) -> Resource<webgpu::GpuExternalTexture> {
-        todo!()
+    ) -> wasmtime::Result<Resource<webgpu::GpuExternalTexture>> {
+        Err(error::not_implemented("gpu-device.import-external-texture"))
     }
 
     fn create_bind_group_layout(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuBindGroupLayoutDescriptor,
-    ) -> Resource<webgpu::GpuBindGroupLayout> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuBindGroupLayout>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        let bind_group_layout = core_result(
+        let label = descriptor.label.clone().unwrap_or_default();
+        if let Err(error) =
+            to_core_conversions::check_bind_group_layout_entries(&descriptor.entries)
+        {
+            error_sink.handle_error(error);
+        }
+        let bind_group_layout = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_bind_group_layout::<crate::Backend>(
-                    device,
-                    &descriptor.to_core(&self.0.table()),
+                    device_id,
+                    &descriptor.to_core(self.0.table())?,
                     None,
                 ),
-        )
-        .unwrap();
+        );
 
-        self.0.table().push(bind_group_layout).unwrap()
+        Ok(self.0.table().push(BindGroupLayout {
+            bind_group_layout,
+            label,
+        })?)
     }
 
     fn create_pipeline_layout(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuPipelineLayoutDescriptor,
-    ) -> Resource<webgpu::GpuPipelineLayout> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuPipelineLayout>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        let pipeline_layout = core_result(
+        let label = descriptor.label.clone().unwrap_or_default();
+        let pipeline_layout = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_pipeline_layout::<crate::Backend>(
-                    device,
-                    &descriptor.to_core(&self.0.table()),
+                    device_id,
+                    &descriptor.to_core(self.0.table())?,
                     None,
                 ),
-        )
-        .unwrap();
+        );
 
-        self.0.table().push(pipeline_layout).unwrap()
+        Ok(self.0.table().push(PipelineLayout {
+            pipeline_layout,
+            label,
+        })?)
     }
 
     fn create_bind_group(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuBindGroupDescriptor,
-    ) -> Resource<webgpu::GpuBindGroup> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuBindGroup>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
-        let bind_group = core_result(
+        let label = descriptor.label.clone().unwrap_or_default();
+        if let Err(error) = to_core_conversions::check_bind_group_entries(&descriptor.entries) {
+            error_sink.handle_error(error);
+        }
+        let bind_group = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_bind_group::<crate::Backend>(
-                    device,
-                    &descriptor.to_core(&self.0.table()),
+                    device_id,
+                    &descriptor.to_core(self.0.table())?,
                     None,
                 ),
-        )
-        .unwrap();
+        );
 
-        self.0.table().push(bind_group).unwrap()
+        Ok(self.0.table().push(BindGroup { bind_group, label })?)
     }
 
     fn create_compute_pipeline(
         &mut self,
         device: Resource<webgpu::GpuDevice>,
         descriptor: webgpu::GpuComputePipelineDescriptor,
-    ) -> Resource<webgpu::GpuComputePipeline> {
-        let device = self.0.table().get(&device).unwrap().device;
+    ) -> wasmtime::Result<Resource<webgpu::GpuComputePipeline>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
 
         let implicit_pipeline_ids = match &descriptor.layout {
             webgpu::GpuPipelineLayoutOrGpuAutoLayoutMode::GpuPipelineLayout(_) => None,
@@ -592,90 +1081,260 @@ impl<T: WasiWebGpuView> webgpu::HostGpuDevice for WasiWebGpuImpl<T> {
             },
         };
 
-        let compute_pipeline = core_result(
+        let label = descriptor.label.clone().unwrap_or_default();
+        let compute_pipeline = error_sink.handle_core_result(
             self.0
                 .instance()
                 .device_create_compute_pipeline::<crate::Backend>(
-                    device,
-                    &descriptor.to_core(&self.0.table()),
+                    device_id,
+                    &descriptor.to_core(self.0.table())?,
                     None,
                     implicit_pipeline_ids,
                 ),
-        )
-        .unwrap();
-        self.0.table().push(compute_pipeline).unwrap()
-    }
-
-    // fn create_compute_pipeline_async(
-    //     &mut self,
-    //     self_: Resource<webgpu::GpuDevice>,
-    //     descriptor: webgpu::GpuComputePipelineDescriptor,
-    // ) -> Resource<webgpu::GpuComputePipeline> {
-    //     todo!()
-    // }
-
-    // fn create_render_pipeline_async(
-    //     &mut self,
-    //     self_: Resource<webgpu::GpuDevice>,
-    //     descriptor: webgpu::GpuRenderPipelineDescriptor,
-    // ) -> Resource<wgpu_core::id::RenderPipelineId> {
-    //     todo!()
-    // }
+        );
+        Ok(self.0.table().push(ComputePipeline {
+            compute_pipeline,
+            error_sink,
+            label,
+        })?)
+    }
+
+    async fn create_compute_pipeline_async(
+        &mut self,
+        device: Resource<webgpu::GpuDevice>,
+        descriptor: webgpu::GpuComputePipelineDescriptor,
+    ) -> wasmtime::Result<
+        Result<Resource<webgpu::GpuComputePipeline>, Resource<webgpu::GpuPipelineError>>,
+    > {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
+
+        let label = descriptor.label.clone().unwrap_or_default();
+        let descriptor = descriptor.to_core(self.0.table())?;
+        let instance = self.0.instance();
+        // Compiling large shaders can take a while, so it happens on wasmtime's blocking pool to keep the guest's other tasks running.
+        let result = wasmtime_wasi::runtime::spawn_blocking(move || {
+            let implicit_pipeline_ids = match descriptor.layout {
+                Some(_) => None,
+                None => Some(wgpu_core::device::ImplicitPipelineIds {
+                    root_id: None,
+                    group_ids: &[None; wgpu_core::MAX_BIND_GROUPS],
+                }),
+            };
+            instance.device_create_compute_pipeline::<crate::Backend>(
+                device_id,
+                &descriptor,
+                None,
+                implicit_pipeline_ids,
+            )
+        })
+        .await;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createcomputepipelineasync
+        // Errors reject the promise rather than going to the device's error scopes.
+        Ok(match result {
+            (compute_pipeline, Some(error)) => {
+                // wgpu-core still registers an invalid id that nothing else will release.
+                self.0
+                    .instance()
+                    .compute_pipeline_drop::<crate::Backend>(compute_pipeline);
+                Err(self
+                    .0
+                    .table()
+                    .push(error::PipelineError::from_core(&error))?)
+            }
+            (compute_pipeline, None) => Ok(self.0.table().push(ComputePipeline {
+                compute_pipeline,
+                error_sink,
+                label,
+            })?),
+        })
+    }
+
+    async fn create_render_pipeline_async(
+        &mut self,
+        device: Resource<webgpu::GpuDevice>,
+        descriptor: webgpu::GpuRenderPipelineDescriptor,
+    ) -> wasmtime::Result<
+        Result<Resource<webgpu::GpuRenderPipeline>, Resource<webgpu::GpuPipelineError>>,
+    > {
+        let host_device = self.0.table().get(&device)?;
+        let device_id = host_device.device;
+        let error_sink = host_device.error_sink.clone();
+
+        let label = descriptor.label.clone().unwrap_or_default();
+        if let Some(Err(error)) = descriptor
+            .depth_stencil
+            .as_ref()
+            .map(to_core_conversions::check_depth_stencil_state)
+        {
+            return Ok(Err(self
+                .0
+                .table()
+                .push(error::PipelineError::from_core(&error))?));
+        }
+        let descriptor = descriptor.to_core(self.0.table())?;
+        let instance = self.0.instance();
+        // Compiling large shaders can take a while, so it happens on wasmtime's blocking pool to keep the guest's other tasks running.
+        let result = wasmtime_wasi::runtime::spawn_blocking(move || {
+            let implicit_pipeline_ids = match descriptor.layout {
+                Some(_) => None,
+                None => Some(wgpu_core::device::ImplicitPipelineIds {
+                    root_id: None,
+                    group_ids: &[None; wgpu_core::MAX_BIND_GROUPS],
+                }),
+            };
+            instance.device_create_render_pipeline::<crate::Backend>(
+                device_id,
+                &descriptor,
+                None,
+                implicit_pipeline_ids,
+            )
+        })
+        .await;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createrenderpipelineasync
+        // Errors reject the promise rather than going to the device's error scopes.
+        Ok(match result {
+            (render_pipeline, Some(error)) => {
+                // wgpu-core still registers an invalid id that nothing else will release.
+                self.0
+                    .instance()
+                    .render_pipeline_drop::<crate::Backend>(render_pipeline);
+                Err(self
+                    .0
+                    .table()
+                    .push(error::PipelineError::from_core(&error))?)
+            }
+            (render_pipeline, None) => Ok(self.0.table().push(RenderPipeline {
+                render_pipeline,
+                error_sink,
+                label,
+            })?),
+        })
+    }
 
     fn create_render_bundle_encoder(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-        _descriptor: webgpu::GpuRenderBundleEncoderDescriptor,
-    ) -> Resource<webgpu::GpuRenderBundleEncoder> {
-        todo!()
+        device: Resource<webgpu::GpuDevice>,
+        descriptor: webgpu::GpuRenderBundleEncoderDescriptor,
+    ) -> wasmtime::Result<Resource<webgpu::GpuRenderBundleEncoder>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
+
+        let label = descriptor.label.clone().unwrap_or_default();
+        let render_bundle_encoder = error_sink
+            .handle_result(wgpu_core::command::RenderBundleEncoder::new(
+                &descriptor.to_core(self.0.table())?,
+                device_id,
+                None,
+            ))
+            // Invalid encoder, so that the error resurfaces on finish.
+            .unwrap_or_else(|| wgpu_core::command::RenderBundleEncoder::dummy(device_id));
+
+        Ok(self.0.table().push(RenderBundleEncoder {
+            render_bundle_encoder,
+            error_sink,
+            label,
+        })?)
     }
 
     fn create_query_set(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-        _descriptor: webgpu::GpuQuerySetDescriptor,
-    ) -> Resource<webgpu::GpuQuerySet> {
-        todo!()
+        device: Resource<webgpu::GpuDevice>,
+        descriptor: webgpu::GpuQuerySetDescriptor,
+    ) -> wasmtime::Result<Resource<webgpu::GpuQuerySet>> {
+        let device = self.0.table().get(&device)?;
+        let device_id = device.device;
+        let error_sink = device.error_sink.clone();
+
+        let type_ = descriptor.type_;
+        let count = descriptor.count;
+        let label = descriptor.label.clone().unwrap_or_default();
+        // Timestamp query sets fail validation here unless the device was created with `timestamp-query`.
+        let query_set = error_sink.handle_core_result(
+            self.0.instance().device_create_query_set::<crate::Backend>(
+                device_id,
+                &descriptor.to_core(self.0.table())?,
+                None,
+            ),
+        );
+        let query_set = QuerySetHandle::new(self.0.instance(), query_set);
+        Ok(self.0.table().push(QuerySet {
+            query_set: Some(query_set),
+            type_,
+            count,
+            label,
+        })?)
     }
 
-    fn label(&mut self, _device: Resource<webgpu::GpuDevice>) -> String {
-        todo!()
+    fn label(&mut self, device: Resource<webgpu::GpuDevice>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&device)?.label.clone())
     }
 
-    fn set_label(&mut self, _device: Resource<webgpu::GpuDevice>, _label: String) -> () {
-        todo!()
+    fn set_label(
+        &mut self,
+        device: Resource<webgpu::GpuDevice>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&device)?.label = label;
+        Ok(())
     }
 
     fn lost(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-    ) -> Resource<webgpu::GpuDeviceLostInfo> {
-        todo!()
+        device: Resource<webgpu::GpuDevice>,
+    ) -> wasmtime::Result<Resource<webgpu::DeviceLostListener>> {
+        let lost = self.0.table().get(&device)?.lost.clone();
+        Ok(self
+            .0
+            .table()
+            .push(device_lost::DeviceLostListener { lost })?)
     }
 
     fn push_error_scope(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-        _filter: webgpu::GpuErrorFilter,
-    ) -> () {
-        todo!()
+        device: Resource<webgpu::GpuDevice>,
+        filter: webgpu::GpuErrorFilter,
+    ) -> wasmtime::Result<()> {
+        let device = self.0.table().get(&device)?;
+        device.error_sink.push_scope(filter);
+        Ok(())
     }
 
     fn pop_error_scope(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-    ) -> Resource<webgpu::GpuError> {
-        todo!()
+        device: Resource<webgpu::GpuDevice>,
+    ) -> wasmtime::Result<Result<Option<webgpu::GpuError>, webgpu::GpuPopErrorScopeError>> {
+        let device = self.0.table().get(&device)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpudevice-poperrorscope
+        let Some(error) = device.error_sink.pop_scope() else {
+            return Ok(Err(webgpu::GpuPopErrorScopeError {
+                kind: webgpu::GpuPopErrorScopeErrorKind::OperationError,
+                message: "The error scope stack is empty".to_string(),
+            }));
+        };
+        let Some(error) = error else {
+            return Ok(Ok(None));
+        };
+        Ok(Ok(Some(error.into_resource(self.0.table())?)))
     }
 
     fn onuncapturederror(
         &mut self,
-        _device: Resource<webgpu::GpuDevice>,
-    ) -> Resource<webgpu::EventHandler> {
-        todo!()
-    }
-
-    fn drop(&mut self, _rep: Resource<webgpu::GpuDevice>) -> wasmtime::Result<()> {
+        device: Resource<webgpu::GpuDevice>,
+    ) -> wasmtime::Result<Resource<webgpu::UncapturedErrorListener>> {
+        let device = self.0.table().get(&device)?;
+        let receiver = device.error_sink.uncaptured_receiver();
+        Ok(self.0.table().push(error::UncapturedErrorListener {
+            receiver,
+            data: Default::default(),
+        })?)
+    }
+
+    fn drop(&mut self, device: Resource<webgpu::GpuDevice>) -> wasmtime::Result<()> {
+        // The device and its queue are released by the poller, once nothing else uses them.
+        self.0.table().delete(device)?;
         Ok(())
     }
 }
@@ -684,536 +1343,1011 @@ impl<T: WasiWebGpuView> webgpu::HostGpuTexture for WasiWebGpuImpl<T> {
     fn from_graphics_buffer(
         &mut self,
         buffer: Resource<GraphicsContextBuffer>,
-    ) -> Resource<wgpu_core::id::TextureId> {
-        let host_buffer = self.0.table().delete(buffer).unwrap();
-        let host_buffer: wgpu_core::id::TextureId = host_buffer.inner_type();
-        self.0.table().push(host_buffer).unwrap()
+    ) -> wasmtime::Result<Resource<Texture>> {
+        let host_buffer = self.0.table().delete(buffer)?;
+        let host_buffer: Texture = host_buffer.inner_type()?;
+        Ok(self.0.table().push(host_buffer)?)
     }
 
     fn create_view(
         &mut self,
-        texture: Resource<wgpu_core::id::TextureId>,
+        texture: Resource<Texture>,
         descriptor: Option<webgpu::GpuTextureViewDescriptor>,
-    ) -> Resource<wgpu_core::id::TextureViewId> {
-        let texture_id = *self.0.table().get(&texture).unwrap();
-        let texture_view = core_result(
-            self.0.instance().texture_create_view::<crate::Backend>(
-                texture_id,
-                &descriptor
-                    .map(|d| d.to_core(&self.0.table()))
-                    .unwrap_or_default(),
-                None,
-            ),
-        )
-        .unwrap();
-        self.0.table().push(texture_view).unwrap()
+    ) -> wasmtime::Result<Resource<webgpu::GpuTextureView>> {
+        let label = descriptor
+            .as_ref()
+            .and_then(|d| d.label.clone())
+            .unwrap_or_default();
+        let descriptor = descriptor
+            .map(|d| d.to_core(self.0.table()))
+            .transpose()?
+            .unwrap_or_default();
+        let texture = self.0.table().get(&texture)?;
+        let texture_id = texture.texture;
+        let error_sink = texture.error_sink.clone();
+        // source: https://www.w3.org/TR/webgpu/#abstract-opdef-compute-render-extent
+        let render_extent = texture
+            .descriptor
+            .compute_render_extent(descriptor.range.base_mip_level);
+        let texture_view = error_sink.handle_core_result(
+            self.0
+                .instance()
+                .texture_create_view::<crate::Backend>(texture_id, &descriptor, None),
+        );
+        Ok(self.0.table().push(TextureView {
+            texture_view,
+            render_extent,
+            error_sink,
+            label,
+        })?)
     }
 
-    fn drop(&mut self, _rep: Resource<wgpu_core::id::TextureId>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(&mut self, texture: Resource<Texture>) -> wasmtime::Result<()> {
+        let texture = self.0.table().delete(texture)?;
+        if !texture.owned_by_surface {
+            self.0
+                .instance()
+                .texture_drop::<crate::Backend>(texture.texture, false);
+        }
         Ok(())
     }
 
-    fn destroy(&mut self, _self_: Resource<webgpu::GpuTexture>) {
-        todo!()
+    fn destroy(&mut self, _self_: Resource<webgpu::GpuTexture>) -> wasmtime::Result<()> {
+        Err(error::not_implemented("gpu-texture.destroy"))
     }
 
-    fn width(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuIntegerCoordinateOut {
-        todo!()
+    fn width(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuIntegerCoordinateOut> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.size.width)
     }
 
-    fn height(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuIntegerCoordinateOut {
-        todo!()
+    fn height(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuIntegerCoordinateOut> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.size.height)
     }
 
     fn depth_or_array_layers(
         &mut self,
-        _self_: Resource<webgpu::GpuTexture>,
-    ) -> webgpu::GpuIntegerCoordinateOut {
-        todo!()
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuIntegerCoordinateOut> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.size.depth_or_array_layers)
     }
 
     fn mip_level_count(
         &mut self,
-        _self_: Resource<webgpu::GpuTexture>,
-    ) -> webgpu::GpuIntegerCoordinateOut {
-        todo!()
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuIntegerCoordinateOut> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.mip_level_count)
     }
 
-    fn sample_count(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuSize32Out {
-        todo!()
+    fn sample_count(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuSize32Out> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.sample_count)
     }
 
-    fn dimension(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuTextureDimension {
-        todo!()
+    fn dimension(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuTextureDimension> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.dimension.into())
     }
 
-    fn format(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuTextureFormat {
-        todo!()
+    fn format(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuTextureFormat> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.format.try_into()?)
     }
 
-    fn usage(&mut self, _self_: Resource<webgpu::GpuTexture>) -> webgpu::GpuFlagsConstant {
-        todo!()
+    fn usage(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+    ) -> wasmtime::Result<webgpu::GpuFlagsConstant> {
+        let texture = self.0.table().get(&texture)?;
+        Ok(texture.descriptor.usage.bits())
     }
 
-    fn label(&mut self, _self_: Resource<webgpu::GpuTexture>) -> String {
-        todo!()
+    fn label(&mut self, texture: Resource<webgpu::GpuTexture>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&texture)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuTexture>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        texture: Resource<webgpu::GpuTexture>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&texture)?.label = label;
+        Ok(())
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuTextureView for WasiWebGpuImpl<T> {
-    fn drop(&mut self, _rep: Resource<wgpu_core::id::TextureViewId>) -> wasmtime::Result<()> {
+    fn drop(&mut self, texture_view: Resource<webgpu::GpuTextureView>) -> wasmtime::Result<()> {
+        let texture_view = self.0.table().delete(texture_view)?;
+        texture_view.error_sink.handle_result(
+            self.0
+                .instance()
+                .texture_view_drop::<crate::Backend>(texture_view.texture_view, false),
+        );
         Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::id::TextureViewId>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        texture_view: Resource<webgpu::GpuTextureView>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&texture_view)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::TextureViewId>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        texture_view: Resource<webgpu::GpuTextureView>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&texture_view)?.label = label;
+        Ok(())
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuCommandBuffer for WasiWebGpuImpl<T> {
-    fn drop(&mut self, _rep: Resource<webgpu::GpuCommandBuffer>) -> wasmtime::Result<()> {
-        // self.web_gpu_host.command_buffers.remove(&rep.rep());
+    fn drop(&mut self, command_buffer: Resource<webgpu::GpuCommandBuffer>) -> wasmtime::Result<()> {
+        let command_buffer = self.0.table().delete(command_buffer)?;
+        self.0
+            .instance()
+            .command_buffer_drop::<crate::Backend>(command_buffer.command_buffer);
         Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::id::CommandBufferId>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        command_buffer: Resource<webgpu::GpuCommandBuffer>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&command_buffer)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::CommandBufferId>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        command_buffer: Resource<webgpu::GpuCommandBuffer>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&command_buffer)?.label = label;
+        Ok(())
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuShaderModule for WasiWebGpuImpl<T> {
-    fn drop(&mut self, _rep: Resource<webgpu::GpuShaderModule>) -> wasmtime::Result<()> {
-        // self.web_gpu_host.shaders.remove(&rep.rep());
+    fn drop(&mut self, shader_module: Resource<webgpu::GpuShaderModule>) -> wasmtime::Result<()> {
+        let shader_module = self.0.table().delete(shader_module)?;
+        self.0
+            .instance()
+            .shader_module_drop::<crate::Backend>(shader_module.shader_module);
         Ok(())
     }
 
     fn get_compilation_info(
         &mut self,
-        _self_: Resource<wgpu_core::id::ShaderModuleId>,
-    ) -> Resource<webgpu::GpuCompilationInfo> {
-        todo!()
+        shader_module: Resource<webgpu::GpuShaderModule>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuCompilationInfo>> {
+        let shader_module = self.0.table().get(&shader_module)?;
+        let compilation_info = compilation_info::CompilationInfo {
+            messages: shader_module.compilation_messages.clone(),
+        };
+        Ok(self.0.table().push(compilation_info)?)
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::id::ShaderModuleId>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        shader_module: Resource<webgpu::GpuShaderModule>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&shader_module)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::ShaderModuleId>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        shader_module: Resource<webgpu::GpuShaderModule>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&shader_module)?.label = label;
+        Ok(())
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuRenderPipeline for WasiWebGpuImpl<T> {
-    fn drop(&mut self, _rep: Resource<webgpu::GpuRenderPipeline>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(
+        &mut self,
+        render_pipeline: Resource<webgpu::GpuRenderPipeline>,
+    ) -> wasmtime::Result<()> {
+        let render_pipeline = self.0.table().delete(render_pipeline)?;
+        self.0
+            .instance()
+            .render_pipeline_drop::<crate::Backend>(render_pipeline.render_pipeline);
         Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::id::RenderPipelineId>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        render_pipeline: Resource<webgpu::GpuRenderPipeline>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&render_pipeline)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::RenderPipelineId>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        render_pipeline: Resource<webgpu::GpuRenderPipeline>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&render_pipeline)?.label = label;
+        Ok(())
     }
 
     fn get_bind_group_layout(
         &mut self,
-        _self_: Resource<wgpu_core::id::RenderPipelineId>,
-        _index: u32,
-    ) -> Resource<webgpu::GpuBindGroupLayout> {
-        todo!()
+        render_pipeline: Resource<webgpu::GpuRenderPipeline>,
+        index: u32,
+    ) -> wasmtime::Result<Resource<webgpu::GpuBindGroupLayout>> {
+        let render_pipeline = self.0.table().get(&render_pipeline)?;
+        let pipeline_id = render_pipeline.render_pipeline;
+        let error_sink = render_pipeline.error_sink.clone();
+        let bind_group_layout = error_sink.handle_core_result(
+            self.0
+                .instance()
+                .render_pipeline_get_bind_group_layout::<crate::Backend>(pipeline_id, index, None),
+        );
+        Ok(self.0.table().push(BindGroupLayout {
+            bind_group_layout,
+            label: String::new(),
+        })?)
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuAdapter for WasiWebGpuImpl<T> {
     fn request_device(
         &mut self,
-        adapter: Resource<wgpu_core::id::AdapterId>,
+        adapter: Resource<webgpu::GpuAdapter>,
         descriptor: Option<webgpu::GpuDeviceDescriptor>,
-    ) -> Resource<webgpu::GpuDevice> {
-        let adapter_id = *self.0.table().get(&adapter).unwrap();
+    ) -> wasmtime::Result<Result<Resource<webgpu::GpuDevice>, webgpu::GpuRequestDeviceError>> {
+        let adapter = self.0.table().get(&adapter)?.adapter.clone();
+        let adapter_id = adapter.id();
 
-        let (device_id, queue_id) = core_results_2(
-            self.0.instance().adapter_request_device::<crate::Backend>(
-                adapter_id,
-                &descriptor
-                    .map(|d| d.to_core(&self.0.table()))
-                    .unwrap_or_default(),
-                None,
-                None,
-                None,
-            ),
-        )
-        .unwrap();
-
-        let device = self
+        // source: https://www.w3.org/TR/webgpu/#dom-gpuadapter-requestdevice
+        let adapter_features = self
             .0
-            .table()
-            .push(Device {
-                device: device_id,
-                queue: queue_id,
-                adapter: adapter_id,
+            .instance()
+            .adapter_features::<crate::Backend>(adapter_id)?;
+        let unsupported_features = descriptor
+            .iter()
+            .flat_map(|d| d.required_features.iter().flatten())
+            .filter(|feature| !adapter_features.contains((**feature).into()))
+            .map(|feature| {
+                enum_conversions::FEATURE_NAMES
+                    .iter()
+                    .find(|(f, _)| f == feature)
+                    .unwrap()
+                    .1
             })
-            .unwrap();
+            .collect::<Vec<_>>();
+        if !unsupported_features.is_empty() {
+            return Ok(Err(webgpu::GpuRequestDeviceError {
+                kind: webgpu::GpuRequestDeviceErrorKind::TypeError,
+                message: format!(
+                    "Required features are not supported by the adapter: {}",
+                    unsupported_features.join(", ")
+                ),
+            }));
+        }
+        let adapter_limits = self
+            .0
+            .instance()
+            .adapter_limits::<crate::Backend>(adapter_id)?;
+        let required_limits = match limits::required_limits(
+            descriptor
+                .as_ref()
+                .and_then(|d| d.required_limits.as_deref())
+                .unwrap_or_default(),
+            &adapter_limits,
+        ) {
+            Ok(required_limits) => required_limits,
+            Err(error) => return Ok(Err(error)),
+        };
 
-        device
-    }
+        let label = descriptor
+            .as_ref()
+            .and_then(|d| d.label.clone())
+            .unwrap_or_default();
+        let queue_label = descriptor
+            .as_ref()
+            .and_then(|d| d.default_queue.as_ref())
+            .and_then(|q| q.label.clone())
+            .unwrap_or_default();
+
+        let mut descriptor: wgpu_types::DeviceDescriptor<wgpu_core::Label> = descriptor
+            .map(|d| d.to_core(self.0.table()))
+            .transpose()?
+            .unwrap_or_default();
+        descriptor.required_limits = required_limits;
+
+        let error_sink = ErrorSink::new();
+        let (device_id, queue_id, error) = self
+            .0
+            .instance()
+            .adapter_request_device::<crate::Backend>(adapter_id, &descriptor, None, None, None);
+
+        let lost = match error {
+            None => {
+                let (device_lost_closure, lost) = device_lost::device_lost_closure();
+                self.0
+                    .instance()
+                    .device_set_device_lost_closure::<crate::Backend>(
+                        device_id,
+                        device_lost_closure,
+                    );
+                lost
+            }
+            // source: https://www.w3.org/TR/webgpu/#dom-gpuadapter-requestdevice
+            // A device that failed to be created is returned already lost.
+            Some(error) => {
+                let lost = device_lost::already_lost(error.to_string());
+                error_sink.handle_error(error);
+                lost
+            }
+        };
 
-    fn drop(&mut self, _adapter: Resource<webgpu::GpuAdapter>) -> wasmtime::Result<()> {
+        let poller = DevicePoller::new(self.0.instance(), adapter, device_id, queue_id);
+        let device = self.0.table().push(Device {
+            device: device_id,
+            queue: queue_id,
+            adapter: adapter_id,
+            error_sink,
+            lost,
+            poller,
+            buffers: DeviceBuffers::default(),
+            label,
+            queue_label,
+        })?;
+
+        Ok(Ok(device))
+    }
+
+    fn drop(&mut self, adapter: Resource<webgpu::GpuAdapter>) -> wasmtime::Result<()> {
+        // The id is released once the devices requested from the adapter are gone too.
+        self.0.table().delete(adapter)?;
         Ok(())
     }
 
     fn features(
         &mut self,
-        _self_: wasmtime::component::Resource<wgpu_core::id::AdapterId>,
-    ) -> wasmtime::component::Resource<webgpu::GpuSupportedFeatures> {
-        todo!()
+        adapter: Resource<webgpu::GpuAdapter>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuSupportedFeatures>> {
+        let adapter = self.0.table().get(&adapter)?.adapter.id();
+        let features = self
+            .0
+            .instance()
+            .adapter_features::<crate::Backend>(adapter)?;
+        Ok(self.0.table().push(features)?)
     }
 
     fn limits(
         &mut self,
-        adapter: Resource<wgpu_core::id::AdapterId>,
-    ) -> Resource<webgpu::GpuSupportedLimits> {
-        let adapter = *self.0.table().get(&adapter).unwrap();
+        adapter: Resource<webgpu::GpuAdapter>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuSupportedLimits>> {
+        let adapter = self.0.table().get(&adapter)?.adapter.id();
         let limits = self
             .0
             .instance()
-            .adapter_limits::<crate::Backend>(adapter)
-            .unwrap();
-        self.0.table().push(limits).unwrap()
+            .adapter_limits::<crate::Backend>(adapter)?;
+        Ok(self.0.table().push(limits)?)
     }
 
     fn is_fallback_adapter(
         &mut self,
-        _self_: wasmtime::component::Resource<wgpu_core::id::AdapterId>,
-    ) -> bool {
-        todo!()
+        adapter: Resource<webgpu::GpuAdapter>,
+    ) -> wasmtime::Result<bool> {
+        let adapter_id = self.0.table().get(&adapter)?.adapter.id();
+        let info = self
+            .0
+            .instance()
+            .adapter_get_info::<crate::Backend>(adapter_id)?;
+        // Same check wgpu-core uses for `force_fallback_adapter`.
+        Ok(info.device_type == wgpu_types::DeviceType::Cpu)
     }
 
     fn request_adapter_info(
         &mut self,
-        adapter: Resource<wgpu_core::id::AdapterId>,
-    ) -> Resource<webgpu::GpuAdapterInfo> {
-        let adapter_id = *self.0.table().get(&adapter).unwrap();
+        adapter: Resource<webgpu::GpuAdapter>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuAdapterInfo>> {
+        let adapter_id = self.0.table().get(&adapter)?.adapter.id();
         let info = self
             .0
             .instance()
-            .adapter_get_info::<crate::Backend>(adapter_id)
-            .unwrap();
-        let info = self.0.table().push(info).unwrap();
-        info
+            .adapter_get_info::<crate::Backend>(adapter_id)?;
+        let info = self.0.table().push(info)?;
+        Ok(info)
     }
 }
 
+#[async_trait::async_trait]
 impl<T: WasiWebGpuView> webgpu::HostGpuQueue for WasiWebGpuImpl<T> {
     fn submit(
         &mut self,
-        queue: Resource<wgpu_core::id::QueueId>,
+        queue: Resource<Queue>,
         val: Vec<Resource<webgpu::GpuCommandBuffer>>,
-    ) {
+    ) -> wasmtime::Result<()> {
         let command_buffers = val
             .into_iter()
-            .map(|buffer| self.0.table().delete(buffer).unwrap())
-            .collect::<Vec<_>>();
+            .map(|buffer| Ok(self.0.table().delete(buffer)?.command_buffer))
+            .collect::<wasmtime::Result<Vec<_>>>()?;
 
-        let queue = *self.0.table().get(&queue).unwrap();
-        self.0
-            .instance()
-            .queue_submit::<crate::Backend>(queue, &command_buffers)
-            .unwrap();
+        let queue = self.0.table().get(&queue)?;
+        let queue_id = queue.queue;
+        let error_sink = queue.error_sink.clone();
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .queue_submit::<crate::Backend>(queue_id, &command_buffers),
+        );
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<wgpu_core::id::QueueId>) -> wasmtime::Result<()> {
-        // todo!()
+    fn drop(&mut self, queue: Resource<Queue>) -> wasmtime::Result<()> {
+        // The queue is released by the device poller, once nothing else uses it.
+        self.0.table().delete(queue)?;
         Ok(())
     }
 
-    fn on_submitted_work_done(&mut self, _self_: Resource<wgpu_core::id::QueueId>) {
-        todo!()
+    async fn on_submitted_work_done(&mut self, queue: Resource<Queue>) -> wasmtime::Result<()> {
+        let queue = self.0.table().get(&queue)?;
+        let queue_id = queue.queue;
+        let poller = queue.poller.clone();
+        let error_sink = queue.error_sink.clone();
+
+        let instance = self.0.instance();
+        let result = CallbackFuture::new(Box::new(
+            move |resolve: Box<
+                dyn FnOnce(Box<Result<(), wgpu_core::device::queue::InvalidQueue>>) + Send,
+            >| {
+                // wgpu-core drops the closure without calling it if the queue is invalid,
+                // in which case the error resolves the future instead.
+                let resolve = Arc::new(Mutex::new(Some(resolve)));
+                let on_done = Arc::clone(&resolve);
+                let closure = wgpu_core::device::queue::SubmittedWorkDoneClosure::from_rust(
+                    Box::new(move || {
+                        if let Some(resolve) = on_done.lock().unwrap().take() {
+                            resolve(Box::new(Ok(())));
+                        }
+                    }),
+                );
+                if let Err(error) =
+                    instance.queue_on_submitted_work_done::<crate::Backend>(queue_id, closure)
+                {
+                    if let Some(resolve) = resolve.lock().unwrap().take() {
+                        resolve(Box::new(Err(error)));
+                    }
+                }
+                // Resolves once the device's poller fires the closure.
+                poller.poll();
+            },
+        ))
+        .await;
+        error_sink.handle_result(*result);
+        Ok(())
     }
 
     fn write_buffer(
         &mut self,
-        queue: Resource<wgpu_core::id::QueueId>,
+        queue: Resource<Queue>,
         buffer: Resource<webgpu::GpuBuffer>,
         buffer_offset: webgpu::GpuSize64,
         data_offset: Option<webgpu::GpuSize64>,
         data: Vec<u8>,
         size: Option<webgpu::GpuSize64>,
-    ) {
-        let queue = *self.0.table().get(&queue).unwrap();
-        let buffer = self.0.table().get(&buffer).unwrap().buffer;
-        let mut data = &data[..];
-        if let Some(data_offset) = data_offset {
-            let data_offset = data_offset as usize;
-            data = &data[data_offset..];
-        }
-        if let Some(size) = size {
-            let size = size as usize;
-            data = &data[..size];
-        }
-        self.0
-            .instance()
-            .queue_write_buffer::<crate::Backend>(queue, buffer, buffer_offset, &data)
-            .unwrap();
+    ) -> wasmtime::Result<()> {
+        let queue = self.0.table().get(&queue)?;
+        let queue_id = queue.queue;
+        let error_sink = queue.error_sink.clone();
+        let buffer = self.0.table().get(&buffer)?.buffer;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpuqueue-writebuffer
+        // Both are in bytes here, since the data is always a byte list.
+        let data_offset = data_offset.unwrap_or(0);
+        let Some(data) = usize::try_from(data_offset)
+            .ok()
+            .and_then(|data_offset| data.get(data_offset..))
+        else {
+            error_sink.handle_error(ValidationError(format!(
+                "Data offset {data_offset} is past the end of the {} bytes of data",
+                data.len()
+            )));
+            return Ok(());
+        };
+        let data = match size {
+            None => data,
+            Some(size) => {
+                let Some(data) = usize::try_from(size).ok().and_then(|size| data.get(..size))
+                else {
+                    error_sink.handle_error(ValidationError(format!(
+                        "Size {size} is larger than the {} bytes of data after the offset",
+                        data.len()
+                    )));
+                    return Ok(());
+                };
+                data
+            }
+        };
+        error_sink.handle_result(self.0.instance().queue_write_buffer::<crate::Backend>(
+            queue_id,
+            buffer,
+            buffer_offset,
+            data,
+        ));
+        Ok(())
     }
 
     fn write_texture(
         &mut self,
-        queue: Resource<wgpu_core::id::QueueId>,
+        queue: Resource<Queue>,
         destination: webgpu::GpuImageCopyTexture,
         data: Vec<u8>,
         data_layout: webgpu::GpuImageDataLayout,
         size: webgpu::GpuExtent3D,
-    ) {
-        let queue = *self.0.table().get(&queue).unwrap();
-        self.0
-            .instance()
-            .queue_write_texture::<crate::Backend>(
-                queue,
-                &destination.to_core(&self.0.table()),
-                &data,
-                &data_layout.to_core(&self.0.table()),
-                &size.to_core(&self.0.table()),
-            )
-            .unwrap();
+    ) -> wasmtime::Result<()> {
+        let queue = self.0.table().get(&queue)?;
+        let queue_id = queue.queue;
+        let error_sink = queue.error_sink.clone();
+        if let Err(error) = to_core_conversions::check_image_copy_texture(&destination)
+            .and_then(|()| to_core_conversions::check_extent(&size))
+        {
+            error_sink.handle_error(error);
+            return Ok(());
+        }
+        error_sink.handle_result(self.0.instance().queue_write_texture::<crate::Backend>(
+            queue_id,
+            &destination.to_core(self.0.table())?,
+            &data,
+            &data_layout.to_core(self.0.table())?,
+            &size.to_core(self.0.table())?,
+        ));
+        Ok(())
     }
 
     fn copy_external_image_to_texture(
         &mut self,
-        _self_: Resource<wgpu_core::id::QueueId>,
+        _self_: Resource<Queue>,
         _source: webgpu::GpuImageCopyExternalImage,
         _destination: webgpu::GpuImageCopyTextureTagged,
         _copy_size: webgpu::GpuExtent3D,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-queue.copy-external-image-to-texture",
+        ))
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::id::QueueId>) -> String {
-        todo!()
+    fn label(&mut self, queue: Resource<Queue>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&queue)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::QueueId>, _label: String) {
-        todo!()
+    fn set_label(&mut self, queue: Resource<Queue>, label: String) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&queue)?.label = label;
+        Ok(())
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuCommandEncoder for WasiWebGpuImpl<T> {
     fn begin_render_pass(
         &mut self,
-        command_encoder: Resource<wgpu_core::id::CommandEncoderId>,
-        descriptor: webgpu::GpuRenderPassDescriptor,
-    ) -> Resource<webgpu::GpuRenderPassEncoder> {
+        command_encoder: Resource<CommandEncoder>,
+        mut descriptor: webgpu::GpuRenderPassDescriptor,
+    ) -> wasmtime::Result<Resource<webgpu::GpuRenderPassEncoder>> {
+        if let Some(Err(error)) = descriptor
+            .depth_stencil_attachment
+            .as_ref()
+            .map(to_core_conversions::check_depth_stencil_attachment)
+        {
+            let error_sink = self.0.table().get(&command_encoder)?.error_sink.clone();
+            error_sink.handle_error(error);
+        }
+        for attachment in &mut descriptor.color_attachments {
+            if let Some(Err(error)) = attachment
+                .clear_value
+                .clone()
+                .map(to_core_conversions::color)
+            {
+                let error_sink = self.0.table().get(&command_encoder)?.error_sink.clone();
+                error_sink.handle_error(error);
+                attachment.clear_value = None;
+            }
+        }
+        // source: https://www.w3.org/TR/webgpu/#dom-gpucommandencoder-beginrenderpass
+        // wgpu-core checks that all attachments have the same size, so any of them will do.
+        let render_view = descriptor
+            .color_attachments
+            .first()
+            .map(|c| &c.view)
+            .or(descriptor
+                .depth_stencil_attachment
+                .as_ref()
+                .map(|d| &d.view));
+        let render_extent = render_view
+            .map(|view| self.0.table().get(view).map(|view| view.render_extent))
+            .transpose()?;
+        let error_sink = self.0.table().get(&command_encoder)?.error_sink.clone();
+        let query_sets = descriptor
+            .occlusion_query_set
+            .iter()
+            .chain(descriptor.timestamp_writes.iter().map(|t| &t.query_set))
+            .map(|query_set| Ok(self.0.table().get(query_set)?.query_set.clone()))
+            .collect::<wasmtime::Result<Option<Vec<_>>>>()?;
+        let query_sets = query_sets.unwrap_or_else(|| {
+            error_sink.handle_error(ValidationError(
+                "Render pass uses a destroyed query set".to_string(),
+            ));
+            descriptor.occlusion_query_set = None;
+            descriptor.timestamp_writes = None;
+            Vec::new()
+        });
         // can't use to_core because depth_stencil_attachment is Option<&x>.
         let depth_stencil_attachment = descriptor
             .depth_stencil_attachment
-            .map(|d| d.to_core(&self.0.table()));
+            .map(|d| d.to_core(self.0.table()))
+            .transpose()?;
+        let timestamp_writes = descriptor
+            .timestamp_writes
+            .map(|t| t.to_core(self.0.table()))
+            .transpose()?;
+        let label = descriptor.label.clone().unwrap_or_default();
         let descriptor = wgpu_core::command::RenderPassDescriptor {
             label: descriptor.label.map(|l| l.into()),
             color_attachments: descriptor
                 .color_attachments
                 .into_iter()
-                .map(|c| Some(c.to_core(&self.0.table())))
-                .collect::<Vec<_>>()
+                .map(|c| c.to_core(self.0.table()).map(Some))
+                .collect::<wasmtime::Result<Vec<_>>>()?
                 .into(),
             depth_stencil_attachment: depth_stencil_attachment.as_ref(),
-            // timestamp_writes: self.timestamp_writes,
-            // occlusion_query_set: self.occlusion_query_set,
+            timestamp_writes: timestamp_writes.as_ref(),
+            occlusion_query_set: descriptor
+                .occlusion_query_set
+                .map(|q| q.to_core(self.0.table()))
+                .transpose()?,
             // TODO: self.max_draw_count not used
-            // TODO: remove default
-            ..Default::default()
         };
         let render_pass = wgpu_core::command::RenderPass::new(
-            command_encoder.to_core(&self.0.table()),
+            command_encoder.to_core(self.0.table())?,
             &descriptor,
         );
 
-        self.0.table().push(render_pass).unwrap()
+        Ok(self.0.table().push(RenderPassEncoder {
+            render_pass,
+            render_extent,
+            _query_sets: query_sets,
+            error_sink,
+            label,
+        })?)
     }
 
     fn finish(
         &mut self,
-        command_encoder: Resource<wgpu_core::id::CommandEncoderId>,
+        command_encoder: Resource<CommandEncoder>,
         descriptor: Option<webgpu::GpuCommandBufferDescriptor>,
-    ) -> Resource<webgpu::GpuCommandBuffer> {
-        let command_encoder = self.0.table().delete(command_encoder).unwrap();
-        let command_buffer = core_result(
+    ) -> wasmtime::Result<Resource<webgpu::GpuCommandBuffer>> {
+        let label = descriptor
+            .as_ref()
+            .and_then(|d| d.label.clone())
+            .unwrap_or_default();
+        let command_encoder = self.0.table().delete(command_encoder)?;
+        let command_buffer = command_encoder.error_sink.handle_core_result(
             self.0.instance().command_encoder_finish::<crate::Backend>(
-                command_encoder,
+                command_encoder.command_encoder,
                 &descriptor
-                    .map(|d| d.to_core(&self.0.table()))
+                    .map(|d| d.to_core(self.0.table()))
+                    .transpose()?
                     .unwrap_or_default(),
             ),
-        )
-        .unwrap();
-        self.0.table().push(command_buffer).unwrap()
+        );
+        Ok(self.0.table().push(CommandBuffer {
+            command_buffer,
+            label,
+        })?)
     }
 
-    fn drop(&mut self, _rep: Resource<wgpu_core::id::CommandEncoderId>) -> wasmtime::Result<()> {
+    fn drop(&mut self, command_encoder: Resource<CommandEncoder>) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().delete(command_encoder)?;
+        self.0
+            .instance()
+            .command_encoder_drop::<crate::Backend>(command_encoder.command_encoder);
         Ok(())
     }
 
     fn begin_compute_pass(
         &mut self,
-        command_encoder: Resource<wgpu_core::id::CommandEncoderId>,
+        command_encoder: Resource<CommandEncoder>,
         descriptor: Option<webgpu::GpuComputePassDescriptor>,
-    ) -> Resource<webgpu::GpuComputePassEncoder> {
-        let command_encoder = self.0.table().get(&command_encoder).unwrap();
+    ) -> wasmtime::Result<Resource<webgpu::GpuComputePassEncoder>> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let error_sink = command_encoder.error_sink.clone();
+        let command_encoder = command_encoder.command_encoder;
+        let (label, mut timestamp_writes) = match descriptor {
+            Some(descriptor) => (descriptor.label, descriptor.timestamp_writes),
+            None => (None, None),
+        };
+        let query_sets = timestamp_writes
+            .iter()
+            .map(|t| Ok(self.0.table().get(&t.query_set)?.query_set.clone()))
+            .collect::<wasmtime::Result<Option<Vec<_>>>>()?;
+        let query_sets = query_sets.unwrap_or_else(|| {
+            error_sink.handle_error(ValidationError(
+                "Compute pass uses a destroyed query set".to_string(),
+            ));
+            timestamp_writes = None;
+            Vec::new()
+        });
+        let timestamp_writes = timestamp_writes
+            .map(|tw| tw.to_core(self.0.table()))
+            .transpose()?;
         let compute_pass = wgpu_core::command::ComputePass::new(
-            *command_encoder,
+            command_encoder,
             &wgpu_core::command::ComputePassDescriptor {
-                label: Default::default(),
-                timestamp_writes: descriptor
-                    .map(|d| d.timestamp_writes.map(|tw| tw.to_core(&self.0.table())))
-                    .flatten()
-                    .as_ref(),
+                label: label.as_deref().map(|l| l.into()),
+                timestamp_writes: timestamp_writes.as_ref(),
             },
         );
-        self.0.table().push(compute_pass).unwrap()
+        Ok(self.0.table().push(ComputePassEncoder {
+            compute_pass,
+            _query_sets: query_sets,
+            label: label.unwrap_or_default(),
+        })?)
     }
 
     fn copy_buffer_to_buffer(
         &mut self,
-        command_encoder: Resource<wgpu_core::id::CommandEncoderId>,
+        command_encoder: Resource<CommandEncoder>,
         source: Resource<webgpu::GpuBuffer>,
         source_offset: webgpu::GpuSize64,
         destination: Resource<webgpu::GpuBuffer>,
         destination_offset: webgpu::GpuSize64,
         size: webgpu::GpuSize64,
-    ) {
-        let command_encoder = *self.0.table().get(&command_encoder).unwrap();
-        let source = self.0.table().get(&source).unwrap().buffer;
-        let destination = self.0.table().get(&destination).unwrap().buffer;
-        self.0
-            .instance()
-            .command_encoder_copy_buffer_to_buffer::<crate::Backend>(
-                command_encoder,
-                source,
-                source_offset,
-                destination,
-                destination_offset,
-                size,
-            )
-            .unwrap();
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        let source = self.0.table().get(&source)?.buffer;
+        let destination = self.0.table().get(&destination)?.buffer;
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_copy_buffer_to_buffer::<crate::Backend>(
+                    command_encoder_id,
+                    source,
+                    source_offset,
+                    destination,
+                    destination_offset,
+                    size,
+                ),
+        );
+        Ok(())
     }
 
     fn copy_buffer_to_texture(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
-        _source: webgpu::GpuImageCopyBuffer,
-        _destination: webgpu::GpuImageCopyTexture,
-        _copy_size: webgpu::GpuExtent3D,
-    ) {
-        todo!()
+        command_encoder: Resource<CommandEncoder>,
+        source: webgpu::GpuImageCopyBuffer,
+        destination: webgpu::GpuImageCopyTexture,
+        copy_size: webgpu::GpuExtent3D,
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        if let Err(error) = to_core_conversions::check_image_copy_texture(&destination)
+            .and_then(|()| to_core_conversions::check_extent(&copy_size))
+        {
+            error_sink.handle_error(error);
+            return Ok(());
+        }
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_copy_buffer_to_texture::<crate::Backend>(
+                    command_encoder_id,
+                    &source.to_core(self.0.table())?,
+                    &destination.to_core(self.0.table())?,
+                    &copy_size.to_core(self.0.table())?,
+                ),
+        );
+        Ok(())
     }
 
     fn copy_texture_to_buffer(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
-        _source: webgpu::GpuImageCopyTexture,
-        _destination: webgpu::GpuImageCopyBuffer,
-        _copy_size: webgpu::GpuExtent3D,
-    ) {
-        todo!()
+        command_encoder: Resource<CommandEncoder>,
+        source: webgpu::GpuImageCopyTexture,
+        destination: webgpu::GpuImageCopyBuffer,
+        copy_size: webgpu::GpuExtent3D,
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        if let Err(error) = to_core_conversions::check_image_copy_texture(&source)
+            .and_then(|()| to_core_conversions::check_extent(&copy_size))
+        {
+            error_sink.handle_error(error);
+            return Ok(());
+        }
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_copy_texture_to_buffer::<crate::Backend>(
+                    command_encoder_id,
+                    &source.to_core(self.0.table())?,
+                    &destination.to_core(self.0.table())?,
+                    &copy_size.to_core(self.0.table())?,
+                ),
+        );
+        Ok(())
     }
 
     fn copy_texture_to_texture(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
-        _source: webgpu::GpuImageCopyTexture,
-        _destination: webgpu::GpuImageCopyTexture,
-        _copy_size: webgpu::GpuExtent3D,
-    ) {
-        todo!()
+        command_encoder: Resource<CommandEncoder>,
+        source: webgpu::GpuImageCopyTexture,
+        destination: webgpu::GpuImageCopyTexture,
+        copy_size: webgpu::GpuExtent3D,
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        if let Err(error) = to_core_conversions::check_image_copy_texture(&source)
+            .and_then(|()| to_core_conversions::check_image_copy_texture(&destination))
+            .and_then(|()| to_core_conversions::check_extent(&copy_size))
+        {
+            error_sink.handle_error(error);
+            return Ok(());
+        }
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_copy_texture_to_texture::<crate::Backend>(
+                    command_encoder_id,
+                    &source.to_core(self.0.table())?,
+                    &destination.to_core(self.0.table())?,
+                    &copy_size.to_core(self.0.table())?,
+                ),
+        );
+        Ok(())
     }
 
     fn clear_buffer(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
-        _buffer: Resource<webgpu::GpuBuffer>,
-        _offset: Option<webgpu::GpuSize64>,
-        _size: Option<webgpu::GpuSize64>,
-    ) {
-        todo!()
+        command_encoder: Resource<CommandEncoder>,
+        buffer: Resource<webgpu::GpuBuffer>,
+        offset: Option<webgpu::GpuSize64>,
+        size: Option<webgpu::GpuSize64>,
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        let buffer = self.0.table().get(&buffer)?.buffer;
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_clear_buffer::<crate::Backend>(
+                    command_encoder_id,
+                    buffer,
+                    // source: https://www.w3.org/TR/webgpu/#dom-gpucommandencoder-clearbuffer
+                    offset.unwrap_or(0),
+                    // `None` clears until the end of the buffer.
+                    size,
+                ),
+        );
+        Ok(())
     }
 
     fn resolve_query_set(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
-        _query_set: Resource<webgpu::GpuQuerySet>,
-        _first_query: webgpu::GpuSize32,
-        _query_count: webgpu::GpuSize32,
-        _destination: Resource<webgpu::GpuBuffer>,
-        _destination_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        command_encoder: Resource<CommandEncoder>,
+        query_set: Resource<webgpu::GpuQuerySet>,
+        first_query: webgpu::GpuSize32,
+        query_count: webgpu::GpuSize32,
+        destination: Resource<webgpu::GpuBuffer>,
+        destination_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let command_encoder = self.0.table().get(&command_encoder)?;
+        let command_encoder_id = command_encoder.command_encoder;
+        let error_sink = command_encoder.error_sink.clone();
+        if self.0.table().get(&query_set)?.query_set.is_none() {
+            error_sink.handle_error(ValidationError(
+                "Can't resolve a destroyed query set".to_string(),
+            ));
+            return Ok(());
+        }
+        let query_set = query_set.to_core(self.0.table())?;
+        let destination = self.0.table().get(&destination)?.buffer;
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_resolve_query_set::<crate::Backend>(
+                    command_encoder_id,
+                    query_set,
+                    first_query,
+                    query_count,
+                    destination,
+                    destination_offset,
+                ),
+        );
+        Ok(())
     }
 
-    fn label(&mut self, command_encoder: Resource<wgpu_core::id::CommandEncoderId>) -> String {
-        let _command_encoder = self.0.table().get(&command_encoder).unwrap();
-        // TODO: return real label
-        String::new()
+    fn label(&mut self, command_encoder: Resource<CommandEncoder>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&command_encoder)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::id::CommandEncoderId>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        command_encoder: Resource<CommandEncoder>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&command_encoder)?.label = label;
+        Ok(())
     }
 
     fn push_debug_group(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
+        _self_: Resource<CommandEncoder>,
         _group_label: String,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-command-encoder.push-debug-group",
+        ))
     }
 
-    fn pop_debug_group(&mut self, _self_: Resource<wgpu_core::id::CommandEncoderId>) {
-        todo!()
+    fn pop_debug_group(&mut self, _self_: Resource<CommandEncoder>) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-command-encoder.pop-debug-group",
+        ))
     }
 
     fn insert_debug_marker(
         &mut self,
-        _self_: Resource<wgpu_core::id::CommandEncoderId>,
+        _self_: Resource<CommandEncoder>,
         _marker_label: String,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-command-encoder.insert-debug-marker",
+        ))
     }
 }
 
 impl<T: WasiWebGpuView> webgpu::HostGpuRenderPassEncoder for WasiWebGpuImpl<T> {
     fn set_pipeline(
         &mut self,
-        render_pass: Resource<wgpu_core::command::RenderPass>,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
         pipeline: Resource<webgpu::GpuRenderPipeline>,
-    ) {
-        let pipeline = pipeline.to_core(&self.0.table());
-        let render_pass = self.0.table().get_mut(&render_pass).unwrap();
+    ) -> wasmtime::Result<()> {
+        let pipeline = pipeline.to_core(self.0.table())?;
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
         wgpu_core::command::render_commands::wgpu_render_pass_set_pipeline(render_pass, pipeline);
+        Ok(())
     }
 
     fn draw(
         &mut self,
-        rpass: Resource<wgpu_core::command::RenderPass>,
+        rpass: Resource<webgpu::GpuRenderPassEncoder>,
         vertex_count: webgpu::GpuSize32,
         instance_count: webgpu::GpuSize32,
         first_vertex: webgpu::GpuSize32,
         first_instance: webgpu::GpuSize32,
-    ) {
-        let rpass = self.0.table().get_mut(&rpass).unwrap();
+    ) -> wasmtime::Result<()> {
+        let rpass = &mut self.0.table().get_mut(&rpass)?.render_pass;
 
         wgpu_core::command::render_commands::wgpu_render_pass_draw(
             rpass,
@@ -1222,321 +2356,560 @@ impl<T: WasiWebGpuView> webgpu::HostGpuRenderPassEncoder for WasiWebGpuImpl<T> {
             first_vertex,
             first_instance,
         );
+        Ok(())
     }
 
     fn end(
         &mut self,
-        rpass: Resource<wgpu_core::command::RenderPass>,
-        non_standard_encoder: Resource<wgpu_core::id::CommandEncoderId>,
-    ) {
-        let encoder = *self.0.table().get(&non_standard_encoder).unwrap();
-        let rpass = self.0.table().delete(rpass).unwrap();
-        self.0
-            .instance()
-            .command_encoder_run_render_pass::<crate::Backend>(encoder, &rpass)
-            .unwrap();
+        rpass: Resource<webgpu::GpuRenderPassEncoder>,
+        non_standard_encoder: Resource<CommandEncoder>,
+    ) -> wasmtime::Result<()> {
+        let encoder = self.0.table().get(&non_standard_encoder)?;
+        let encoder_id = encoder.command_encoder;
+        let error_sink = encoder.error_sink.clone();
+        let rpass = self.0.table().delete(rpass)?;
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_run_render_pass::<crate::Backend>(encoder_id, &rpass.render_pass),
+        );
+        Ok(())
     }
 
-    fn drop(&mut self, cwr: Resource<wgpu_core::command::RenderPass>) -> wasmtime::Result<()> {
-        self.0.table().delete(cwr).unwrap();
+    fn drop(&mut self, cwr: Resource<webgpu::GpuRenderPassEncoder>) -> wasmtime::Result<()> {
+        self.0.table().delete(cwr)?;
         Ok(())
     }
 
     fn set_viewport(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _x: f32,
-        _y: f32,
-        _width: f32,
-        _height: f32,
-        _min_depth: f32,
-        _max_depth: f32,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        min_depth: f32,
+        max_depth: f32,
+    ) -> wasmtime::Result<()> {
+        let render_pass = self.0.table().get_mut(&render_pass)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurenderpassencoder-setviewport
+        let fits = render_pass.render_extent.is_none_or(|extent| {
+            x + width <= extent.width as f32 && y + height <= extent.height as f32
+        });
+        let valid = x >= 0.0
+            && y >= 0.0
+            && width >= 0.0
+            && height >= 0.0
+            && fits
+            && (0.0..=1.0).contains(&min_depth)
+            && (0.0..=1.0).contains(&max_depth)
+            && min_depth <= max_depth;
+        if !valid {
+            render_pass.error_sink.handle_error(ValidationError(format!(
+                "Viewport {x}, {y}, {width}x{height} and depth {min_depth}..{max_depth} doesn't fit the render target"
+            )));
+            return Ok(());
+        }
+        let render_pass = &mut render_pass.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_set_viewport(
+            render_pass,
+            x,
+            y,
+            width,
+            height,
+            min_depth,
+            max_depth,
+        );
+        Ok(())
     }
 
     fn set_scissor_rect(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _x: webgpu::GpuIntegerCoordinate,
-        _y: webgpu::GpuIntegerCoordinate,
-        _width: webgpu::GpuIntegerCoordinate,
-        _height: webgpu::GpuIntegerCoordinate,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        x: webgpu::GpuIntegerCoordinate,
+        y: webgpu::GpuIntegerCoordinate,
+        width: webgpu::GpuIntegerCoordinate,
+        height: webgpu::GpuIntegerCoordinate,
+    ) -> wasmtime::Result<()> {
+        let render_pass = self.0.table().get_mut(&render_pass)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurenderpassencoder-setscissorrect
+        let fits = render_pass.render_extent.is_none_or(|extent| {
+            u64::from(x) + u64::from(width) <= u64::from(extent.width)
+                && u64::from(y) + u64::from(height) <= u64::from(extent.height)
+        });
+        if !fits {
+            render_pass.error_sink.handle_error(ValidationError(format!(
+                "Scissor rect {x}, {y}, {width}x{height} is outside the render target"
+            )));
+            return Ok(());
+        }
+        let render_pass = &mut render_pass.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_set_scissor_rect(
+            render_pass,
+            x,
+            y,
+            width,
+            height,
+        );
+        Ok(())
     }
 
     fn set_blend_constant(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _color: webgpu::GpuColor,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        color: webgpu::GpuColor,
+    ) -> wasmtime::Result<()> {
+        let render_pass = self.0.table().get_mut(&render_pass)?;
+        let color = match to_core_conversions::color(color) {
+            Ok(color) => color,
+            Err(error) => {
+                render_pass.error_sink.handle_error(error);
+                return Ok(());
+            }
+        };
+        let render_pass = &mut render_pass.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_set_blend_constant(
+            render_pass,
+            &color,
+        );
+        Ok(())
     }
 
     fn set_stencil_reference(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _reference: webgpu::GpuStencilValue,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        reference: webgpu::GpuStencilValue,
+    ) -> wasmtime::Result<()> {
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_set_stencil_reference(
+            render_pass,
+            reference,
+        );
+        Ok(())
     }
 
     fn begin_occlusion_query(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _query_index: webgpu::GpuSize32,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        query_index: webgpu::GpuSize32,
+    ) -> wasmtime::Result<()> {
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_begin_occlusion_query(
+            render_pass,
+            query_index,
+        );
+        Ok(())
     }
 
-    fn end_occlusion_query(&mut self, _self_: Resource<wgpu_core::command::RenderPass>) {
-        todo!()
+    fn end_occlusion_query(
+        &mut self,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+    ) -> wasmtime::Result<()> {
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_end_occlusion_query(render_pass);
+        Ok(())
     }
 
     fn execute_bundles(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _bundles: Vec<Resource<webgpu::GpuRenderBundle>>,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        bundles: Vec<Resource<webgpu::GpuRenderBundle>>,
+    ) -> wasmtime::Result<()> {
+        let bundles = bundles
+            .into_iter()
+            .map(|bundle| bundle.to_core(self.0.table()))
+            .collect::<wasmtime::Result<Vec<_>>>()?;
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
+        wgpu_core::command::render_commands::wgpu_render_pass_execute_bundles(
+            render_pass,
+            &bundles,
+        );
+        Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<wgpu_core::command::RenderPass>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&render_pass)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<wgpu_core::command::RenderPass>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&render_pass)?.label = label;
+        Ok(())
     }
 
     fn push_debug_group(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
+        _self_: Resource<webgpu::GpuRenderPassEncoder>,
         _group_label: String,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-render-pass-encoder.push-debug-group",
+        ))
     }
 
-    fn pop_debug_group(&mut self, _self_: Resource<wgpu_core::command::RenderPass>) {
-        todo!()
+    fn pop_debug_group(
+        &mut self,
+        _self_: Resource<webgpu::GpuRenderPassEncoder>,
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-render-pass-encoder.pop-debug-group",
+        ))
     }
 
     fn insert_debug_marker(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
+        _self_: Resource<webgpu::GpuRenderPassEncoder>,
         _marker_label: String,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented(
+            "gpu-render-pass-encoder.insert-debug-marker",
+        ))
     }
 
     fn set_bind_group(
         &mut self,
-        render_pass: Resource<wgpu_core::command::RenderPass>,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
         index: webgpu::GpuIndex32,
         bind_group: Resource<webgpu::GpuBindGroup>,
         dynamic_offsets: Option<Vec<webgpu::GpuBufferDynamicOffset>>,
-    ) {
-        let bind_group = *self.0.table().get(&bind_group).unwrap();
-        let mut render_pass = self.0.table().get_mut(&render_pass).unwrap();
+    ) -> wasmtime::Result<()> {
+        let bind_group = self.0.table().get(&bind_group)?.bind_group;
+        let render_pass = &mut self.0.table().get_mut(&render_pass)?.render_pass;
 
-        let dynamic_offsets = dynamic_offsets.unwrap();
+        let dynamic_offsets = dynamic_offsets.unwrap_or_default();
         wgpu_core::command::render_commands::wgpu_render_pass_set_bind_group(
-            &mut render_pass,
+            render_pass,
             index,
             bind_group,
             &dynamic_offsets,
-        )
+        );
+        Ok(())
     }
 
     fn set_index_buffer(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _buffer: Resource<webgpu::GpuBuffer>,
-        _index_format: webgpu::GpuIndexFormat,
-        _offset: webgpu::GpuSize64,
-        _size: webgpu::GpuSize64,
-    ) {
-        todo!()
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
+        buffer: Resource<webgpu::GpuBuffer>,
+        index_format: webgpu::GpuIndexFormat,
+        offset: Option<webgpu::GpuSize64>,
+        size: Option<webgpu::GpuSize64>,
+    ) -> wasmtime::Result<()> {
+        let buffer_id = self.0.table().get(&buffer)?.buffer;
+        let render_pass = self.0.table().get_mut(&render_pass)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-setindexbuffer
+        let size = match to_core_conversions::buffer_binding_size("index", size) {
+            Ok(size) => size,
+            Err(error) => {
+                render_pass.error_sink.handle_error(error);
+                return Ok(());
+            }
+        };
+        wgpu_core::command::render_commands::wgpu_render_pass_set_index_buffer(
+            &mut render_pass.render_pass,
+            buffer_id,
+            index_format.into(),
+            offset.unwrap_or(0),
+            size,
+        );
+        Ok(())
     }
 
     fn set_vertex_buffer(
         &mut self,
-        render_pass: Resource<wgpu_core::command::RenderPass>,
+        render_pass: Resource<webgpu::GpuRenderPassEncoder>,
         slot: webgpu::GpuIndex32,
         buffer: Resource<webgpu::GpuBuffer>,
-        offset: webgpu::GpuSize64,
-        size: webgpu::GpuSize64,
-    ) {
-        let buffer_id = self.0.table().get(&buffer).unwrap().buffer;
-        let mut render_pass = self.0.table().get_mut(&render_pass).unwrap();
-
+        offset: Option<webgpu::GpuSize64>,
+        size: Option<webgpu::GpuSize64>,
+    ) -> wasmtime::Result<()> {
+        let buffer_id = self.0.table().get(&buffer)?.buffer;
+        let render_pass = self.0.table().get_mut(&render_pass)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-setvertexbuffer
+        let size = match to_core_conversions::buffer_binding_size("vertex", size) {
+            Ok(size) => size,
+            Err(error) => {
+                render_pass.error_sink.handle_error(error);
+                return Ok(());
+            }
+        };
         wgpu_core::command::render_commands::wgpu_render_pass_set_vertex_buffer(
-            &mut render_pass,
+            &mut render_pass.render_pass,
             slot,
             buffer_id,
-            offset,
-            Some(size.try_into().unwrap()),
+            offset.unwrap_or(0),
+            size,
         );
+        Ok(())
     }
 
     fn draw_indexed(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _index_count: webgpu::GpuSize32,
-        _instance_count: webgpu::GpuSize32,
-        _first_index: webgpu::GpuSize32,
-        _base_vertex: webgpu::GpuSignedOffset32,
-        _first_instance: webgpu::GpuSize32,
-    ) {
-        todo!()
+        rpass: Resource<webgpu::GpuRenderPassEncoder>,
+        index_count: webgpu::GpuSize32,
+        instance_count: webgpu::GpuSize32,
+        first_index: webgpu::GpuSize32,
+        base_vertex: webgpu::GpuSignedOffset32,
+        first_instance: webgpu::GpuSize32,
+    ) -> wasmtime::Result<()> {
+        let rpass = &mut self.0.table().get_mut(&rpass)?.render_pass;
+
+        wgpu_core::command::render_commands::wgpu_render_pass_draw_indexed(
+            rpass,
+            index_count,
+            instance_count,
+            first_index,
+            base_vertex,
+            first_instance,
+        );
+        Ok(())
     }
 
     fn draw_indirect(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _indirect_buffer: Resource<webgpu::GpuBuffer>,
-        _indirect_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        rpass: Resource<webgpu::GpuRenderPassEncoder>,
+        indirect_buffer: Resource<webgpu::GpuBuffer>,
+        indirect_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let indirect_buffer = self.0.table().get(&indirect_buffer)?.buffer;
+        let rpass = &mut self.0.table().get_mut(&rpass)?.render_pass;
+
+        wgpu_core::command::render_commands::wgpu_render_pass_draw_indirect(
+            rpass,
+            indirect_buffer,
+            indirect_offset,
+        );
+        Ok(())
     }
 
     fn draw_indexed_indirect(
         &mut self,
-        _self_: Resource<wgpu_core::command::RenderPass>,
-        _indirect_buffer: Resource<webgpu::GpuBuffer>,
-        _indirect_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        rpass: Resource<webgpu::GpuRenderPassEncoder>,
+        indirect_buffer: Resource<webgpu::GpuBuffer>,
+        indirect_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let indirect_buffer = self.0.table().get(&indirect_buffer)?.buffer;
+        let rpass = &mut self.0.table().get_mut(&rpass)?.render_pass;
+
+        wgpu_core::command::render_commands::wgpu_render_pass_draw_indexed_indirect(
+            rpass,
+            indirect_buffer,
+            indirect_offset,
+        );
+        Ok(())
     }
 }
 
+impl<T: WasiWebGpuView> webgpu::HostUncapturedErrorListener for WasiWebGpuImpl<T> {
+    fn subscribe(
+        &mut self,
+        listener: Resource<webgpu::UncapturedErrorListener>,
+    ) -> wasmtime::Result<Resource<webgpu::Pollable>> {
+        wasmtime_wasi::subscribe(self.0.table(), listener)
+    }
+
+    fn get(
+        &mut self,
+        listener: Resource<webgpu::UncapturedErrorListener>,
+    ) -> wasmtime::Result<Option<Resource<webgpu::GpuUncapturedErrorEvent>>> {
+        let listener = self.0.table().get(&listener)?;
+        let Some(error) = listener.data.lock().unwrap().take() else {
+            return Ok(None);
+        };
+        Ok(Some(
+            self.0.table().push(error::UncapturedErrorEvent { error })?,
+        ))
+    }
+
+    fn drop(
+        &mut self,
+        listener: Resource<webgpu::UncapturedErrorListener>,
+    ) -> wasmtime::Result<()> {
+        self.0.table().delete(listener)?;
+        Ok(())
+    }
+}
 impl<T: WasiWebGpuView> webgpu::HostGpuUncapturedErrorEvent for WasiWebGpuImpl<T> {
     fn new(
         &mut self,
         _type_: String,
-        _gpu_uncaptured_error_event_init_dict: webgpu::GpuUncapturedErrorEventInit,
-    ) -> Resource<webgpu::GpuUncapturedErrorEvent> {
-        todo!()
+        gpu_uncaptured_error_event_init_dict: webgpu::GpuUncapturedErrorEventInit,
+    ) -> wasmtime::Result<Resource<webgpu::GpuUncapturedErrorEvent>> {
+        let error = error::GpuErrorInfo::from_resource(
+            gpu_uncaptured_error_event_init_dict.error,
+            self.0.table(),
+        )?;
+        Ok(self.0.table().push(error::UncapturedErrorEvent { error })?)
     }
 
     fn error(
         &mut self,
-        _self_: Resource<webgpu::GpuUncapturedErrorEvent>,
-    ) -> Resource<webgpu::GpuError> {
-        todo!()
+        event: Resource<webgpu::GpuUncapturedErrorEvent>,
+    ) -> wasmtime::Result<webgpu::GpuError> {
+        let error = self.0.table().get(&event)?.error.clone();
+        error.into_resource(self.0.table())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuUncapturedErrorEvent>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, event: Resource<webgpu::GpuUncapturedErrorEvent>) -> wasmtime::Result<()> {
+        self.0.table().delete(event)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuInternalError for WasiWebGpuImpl<T> {
-    fn new(&mut self, _message: String) -> Resource<webgpu::GpuInternalError> {
-        todo!()
+    fn new(&mut self, message: String) -> wasmtime::Result<Resource<webgpu::GpuInternalError>> {
+        Ok(self.0.table().push(error::GpuErrorInfo {
+            kind: webgpu::GpuErrorFilter::Internal,
+            message,
+        })?)
     }
 
-    fn message(&mut self, _self_: Resource<webgpu::GpuInternalError>) -> String {
-        todo!()
+    fn message(&mut self, error: Resource<webgpu::GpuInternalError>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&error)?.message.clone())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuInternalError>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, error: Resource<webgpu::GpuInternalError>) -> wasmtime::Result<()> {
+        self.0.table().delete(error)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuOutOfMemoryError for WasiWebGpuImpl<T> {
-    fn new(&mut self, _message: String) -> Resource<webgpu::GpuOutOfMemoryError> {
-        todo!()
+    fn new(&mut self, message: String) -> wasmtime::Result<Resource<webgpu::GpuOutOfMemoryError>> {
+        Ok(self.0.table().push(error::GpuErrorInfo {
+            kind: webgpu::GpuErrorFilter::OutOfMemory,
+            message,
+        })?)
     }
 
-    fn message(&mut self, _self_: Resource<webgpu::GpuOutOfMemoryError>) -> String {
-        todo!()
+    fn message(
+        &mut self,
+        error: Resource<webgpu::GpuOutOfMemoryError>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&error)?.message.clone())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuOutOfMemoryError>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, error: Resource<webgpu::GpuOutOfMemoryError>) -> wasmtime::Result<()> {
+        self.0.table().delete(error)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuValidationError for WasiWebGpuImpl<T> {
-    fn new(&mut self, _message: String) -> Resource<webgpu::GpuValidationError> {
-        todo!()
+    fn new(&mut self, message: String) -> wasmtime::Result<Resource<webgpu::GpuValidationError>> {
+        Ok(self.0.table().push(error::GpuErrorInfo {
+            kind: webgpu::GpuErrorFilter::Validation,
+            message,
+        })?)
+    }
+
+    fn message(&mut self, error: Resource<webgpu::GpuValidationError>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&error)?.message.clone())
     }
 
-    fn message(&mut self, _self_: Resource<webgpu::GpuValidationError>) -> String {
-        todo!()
+    fn drop(&mut self, error: Resource<webgpu::GpuValidationError>) -> wasmtime::Result<()> {
+        self.0.table().delete(error)?;
+        Ok(())
+    }
+}
+impl<T: WasiWebGpuView> webgpu::HostDeviceLostListener for WasiWebGpuImpl<T> {
+    fn subscribe(
+        &mut self,
+        listener: Resource<webgpu::DeviceLostListener>,
+    ) -> wasmtime::Result<Resource<webgpu::Pollable>> {
+        wasmtime_wasi::subscribe(self.0.table(), listener)
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuValidationError>) -> wasmtime::Result<()> {
-        todo!()
-    }
-}
-impl<T: WasiWebGpuView> webgpu::HostGpuError for WasiWebGpuImpl<T> {
-    fn message(&mut self, _self_: Resource<webgpu::GpuError>) -> String {
-        todo!()
+    fn get(
+        &mut self,
+        listener: Resource<webgpu::DeviceLostListener>,
+    ) -> wasmtime::Result<Option<Resource<webgpu::GpuDeviceLostInfo>>> {
+        let Some(info) = self.0.table().get(&listener)?.info() else {
+            return Ok(None);
+        };
+        Ok(Some(self.0.table().push(info)?))
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuError>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, listener: Resource<webgpu::DeviceLostListener>) -> wasmtime::Result<()> {
+        self.0.table().delete(listener)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuDeviceLostInfo for WasiWebGpuImpl<T> {
     fn reason(
         &mut self,
-        _self_: Resource<webgpu::GpuDeviceLostInfo>,
-    ) -> webgpu::GpuDeviceLostReason {
-        todo!()
+        info: Resource<webgpu::GpuDeviceLostInfo>,
+    ) -> wasmtime::Result<webgpu::GpuDeviceLostReason> {
+        Ok(self.0.table().get(&info)?.reason)
     }
 
-    fn message(&mut self, _self_: Resource<webgpu::GpuDeviceLostInfo>) -> String {
-        todo!()
+    fn message(&mut self, info: Resource<webgpu::GpuDeviceLostInfo>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&info)?.message.clone())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuDeviceLostInfo>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, info: Resource<webgpu::GpuDeviceLostInfo>) -> wasmtime::Result<()> {
+        self.0.table().delete(info)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuCanvasContext for WasiWebGpuImpl<T> {
     fn canvas(
         &mut self,
         _self_: Resource<webgpu::GpuCanvasContext>,
-    ) -> webgpu::HtmlCanvasElementOrOffscreenCanvas {
-        todo!()
+    ) -> wasmtime::Result<webgpu::HtmlCanvasElementOrOffscreenCanvas> {
+        Err(error::not_implemented("gpu-canvas-context.canvas"))
     }
 
     fn configure(
         &mut self,
         _self_: Resource<webgpu::GpuCanvasContext>,
         _configuration: webgpu::GpuCanvasConfiguration,
-    ) {
-        todo!()
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented("gpu-canvas-context.configure"))
     }
 
-    fn unconfigure(&mut self, _self_: Resource<webgpu::GpuCanvasContext>) {
-        todo!()
+    fn unconfigure(&mut self, _self_: Resource<webgpu::GpuCanvasContext>) -> wasmtime::Result<()> {
+        Err(error::not_implemented("gpu-canvas-context.unconfigure"))
     }
 
     fn get_current_texture(
         &mut self,
         _self_: Resource<webgpu::GpuCanvasContext>,
-    ) -> Resource<webgpu::GpuTexture> {
-        todo!()
+    ) -> wasmtime::Result<Resource<webgpu::GpuTexture>> {
+        Err(error::not_implemented(
+            "gpu-canvas-context.get-current-texture",
+        ))
     }
 
     fn drop(&mut self, _rep: Resource<webgpu::GpuCanvasContext>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("gpu-canvas-context.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuRenderBundle for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuRenderBundle>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        render_bundle: Resource<webgpu::GpuRenderBundle>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&render_bundle)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuRenderBundle>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        render_bundle: Resource<webgpu::GpuRenderBundle>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&render_bundle)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuRenderBundle>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, render_bundle: Resource<webgpu::GpuRenderBundle>) -> wasmtime::Result<()> {
+        let render_bundle = self.0.table().delete(render_bundle)?;
+        self.0
+            .instance()
+            .render_bundle_drop::<crate::Backend>(render_bundle.render_bundle);
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuComputePassEncoder for WasiWebGpuImpl<T> {
@@ -1544,10 +2917,14 @@ impl<T: WasiWebGpuView> webgpu::HostGpuComputePassEncoder for WasiWebGpuImpl<T>
         &mut self,
         encoder: Resource<webgpu::GpuComputePassEncoder>,
         pipeline: Resource<webgpu::GpuComputePipeline>,
-    ) {
-        let pipeline = *self.0.table().get(&pipeline).unwrap();
-        let encoder = self.0.table().get_mut(&encoder).unwrap();
-        wgpu_core::command::compute_commands::wgpu_compute_pass_set_pipeline(encoder, pipeline);
+    ) -> wasmtime::Result<()> {
+        let pipeline = self.0.table().get(&pipeline)?.compute_pipeline;
+        let encoder = self.0.table().get_mut(&encoder)?;
+        wgpu_core::command::compute_commands::wgpu_compute_pass_set_pipeline(
+            &mut encoder.compute_pass,
+            pipeline,
+        );
+        Ok(())
     }
 
     fn dispatch_workgroups(
@@ -1556,67 +2933,107 @@ impl<T: WasiWebGpuView> webgpu::HostGpuComputePassEncoder for WasiWebGpuImpl<T>
         workgroup_count_x: webgpu::GpuSize32,
         workgroup_count_y: Option<webgpu::GpuSize32>,
         workgroup_count_z: Option<webgpu::GpuSize32>,
-    ) {
-        let encoder = self.0.table().get_mut(&encoder).unwrap();
+    ) -> wasmtime::Result<()> {
+        let encoder = self.0.table().get_mut(&encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpucomputepassencoder-dispatchworkgroups
         wgpu_core::command::compute_commands::wgpu_compute_pass_dispatch_workgroups(
-            encoder,
+            &mut encoder.compute_pass,
             workgroup_count_x,
-            workgroup_count_y.unwrap(),
-            workgroup_count_z.unwrap(),
+            workgroup_count_y.unwrap_or(1),
+            workgroup_count_z.unwrap_or(1),
         );
+        Ok(())
     }
 
     fn dispatch_workgroups_indirect(
         &mut self,
-        _self_: Resource<webgpu::GpuComputePassEncoder>,
-        _indirect_buffer: Resource<webgpu::GpuBuffer>,
-        _indirect_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        encoder: Resource<webgpu::GpuComputePassEncoder>,
+        indirect_buffer: Resource<webgpu::GpuBuffer>,
+        indirect_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let indirect_buffer = self.0.table().get(&indirect_buffer)?.buffer;
+        let encoder = self.0.table().get_mut(&encoder)?;
+        wgpu_core::command::compute_commands::wgpu_compute_pass_dispatch_workgroups_indirect(
+            &mut encoder.compute_pass,
+            indirect_buffer,
+            indirect_offset,
+        );
+        Ok(())
     }
 
     fn end(
         &mut self,
-        cpass: Resource<wgpu_core::command::ComputePass>,
-        non_standard_encoder: Resource<wgpu_core::id::CommandEncoderId>,
-    ) {
-        let encoder = *self.0.table().get(&non_standard_encoder).unwrap();
-        let cpass = self.0.table().delete(cpass).unwrap();
-        self.0
-            .instance()
-            .command_encoder_run_compute_pass::<crate::Backend>(encoder, &cpass)
-            .unwrap();
+        cpass: Resource<webgpu::GpuComputePassEncoder>,
+        non_standard_encoder: Resource<CommandEncoder>,
+    ) -> wasmtime::Result<()> {
+        let encoder = self.0.table().get(&non_standard_encoder)?;
+        let encoder_id = encoder.command_encoder;
+        let error_sink = encoder.error_sink.clone();
+        let cpass = self.0.table().delete(cpass)?;
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .command_encoder_run_compute_pass::<crate::Backend>(
+                    encoder_id,
+                    &cpass.compute_pass,
+                ),
+        );
+        Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<webgpu::GpuComputePassEncoder>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        cpass: Resource<webgpu::GpuComputePassEncoder>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&cpass)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuComputePassEncoder>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        cpass: Resource<webgpu::GpuComputePassEncoder>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&cpass)?.label = label;
+        Ok(())
     }
 
     fn push_debug_group(
         &mut self,
-        _self_: Resource<webgpu::GpuComputePassEncoder>,
-        _group_label: String,
-    ) {
-        todo!()
+        cpass: Resource<webgpu::GpuComputePassEncoder>,
+        group_label: String,
+    ) -> wasmtime::Result<()> {
+        let cpass = self.0.table().get_mut(&cpass)?;
+        wgpu_core::command::compute_commands::wgpu_compute_pass_push_debug_group(
+            &mut cpass.compute_pass,
+            &group_label,
+            0,
+        );
+        Ok(())
     }
 
-    fn pop_debug_group(&mut self, _self_: Resource<webgpu::GpuComputePassEncoder>) {
-        todo!()
+    fn pop_debug_group(
+        &mut self,
+        cpass: Resource<webgpu::GpuComputePassEncoder>,
+    ) -> wasmtime::Result<()> {
+        let cpass = self.0.table().get_mut(&cpass)?;
+        wgpu_core::command::compute_commands::wgpu_compute_pass_pop_debug_group(
+            &mut cpass.compute_pass,
+        );
+        Ok(())
     }
 
     fn insert_debug_marker(
         &mut self,
         cpass: Resource<webgpu::GpuComputePassEncoder>,
         label: String,
-    ) {
-        let cpass = self.0.table().get_mut(&cpass).unwrap();
+    ) -> wasmtime::Result<()> {
+        let cpass = self.0.table().get_mut(&cpass)?;
         wgpu_core::command::compute_commands::wgpu_compute_pass_insert_debug_marker(
-            cpass, &label, 0,
+            &mut cpass.compute_pass,
+            &label,
+            0,
         );
+        Ok(())
     }
 
     fn set_bind_group(
@@ -1625,335 +3042,614 @@ impl<T: WasiWebGpuView> webgpu::HostGpuComputePassEncoder for WasiWebGpuImpl<T>
         index: webgpu::GpuIndex32,
         bind_group: Resource<webgpu::GpuBindGroup>,
         dynamic_offsets: Option<Vec<webgpu::GpuBufferDynamicOffset>>,
-    ) {
-        let bind_group = *self.0.table().get(&bind_group).unwrap();
-        let encoder = self.0.table().get_mut(&encoder).unwrap();
-        let dynamic_offsets = dynamic_offsets.unwrap();
+    ) -> wasmtime::Result<()> {
+        let bind_group = self.0.table().get(&bind_group)?.bind_group;
+        let encoder = self.0.table().get_mut(&encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#gpubindingcommandsmixin-setbindgroup
+        let dynamic_offsets = dynamic_offsets.unwrap_or_default();
         wgpu_core::command::compute_commands::wgpu_compute_pass_set_bind_group(
-            encoder,
+            &mut encoder.compute_pass,
             index,
             bind_group,
             &dynamic_offsets,
-        )
+        );
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuComputePassEncoder>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, cpass: Resource<webgpu::GpuComputePassEncoder>) -> wasmtime::Result<()> {
+        // Dropping a pass without ending it just discards the recorded commands.
+        self.0.table().delete(cpass)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuPipelineError for WasiWebGpuImpl<T> {
     fn new(
         &mut self,
-        _message: Option<String>,
-        _options: webgpu::GpuPipelineErrorInit,
-    ) -> Resource<webgpu::GpuPipelineError> {
-        todo!()
+        message: Option<String>,
+        options: webgpu::GpuPipelineErrorInit,
+    ) -> wasmtime::Result<Resource<webgpu::GpuPipelineError>> {
+        let error = error::PipelineError {
+            message: message.unwrap_or_default(),
+            reason: options.reason,
+        };
+        Ok(self.0.table().push(error)?)
+    }
+
+    fn message(&mut self, error: Resource<webgpu::GpuPipelineError>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&error)?.message.clone())
     }
 
     fn reason(
         &mut self,
-        _self_: Resource<webgpu::GpuPipelineError>,
-    ) -> webgpu::GpuPipelineErrorReason {
-        todo!()
+        error: Resource<webgpu::GpuPipelineError>,
+    ) -> wasmtime::Result<webgpu::GpuPipelineErrorReason> {
+        Ok(self.0.table().get(&error)?.reason)
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuPipelineError>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, error: Resource<webgpu::GpuPipelineError>) -> wasmtime::Result<()> {
+        self.0.table().delete(error)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuCompilationMessage for WasiWebGpuImpl<T> {
-    fn message(&mut self, _self_: Resource<webgpu::GpuCompilationMessage>) -> String {
-        todo!()
+    fn message(
+        &mut self,
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&message)?.message.clone())
     }
 
     fn type_(
         &mut self,
-        _self_: Resource<webgpu::GpuCompilationMessage>,
-    ) -> webgpu::GpuCompilationMessageType {
-        todo!()
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<webgpu::GpuCompilationMessageType> {
+        Ok(self.0.table().get(&message)?.type_)
     }
 
-    fn line_num(&mut self, _self_: Resource<webgpu::GpuCompilationMessage>) -> u64 {
-        todo!()
+    fn line_num(
+        &mut self,
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<u64> {
+        Ok(self.0.table().get(&message)?.line_num)
     }
 
-    fn line_pos(&mut self, _self_: Resource<webgpu::GpuCompilationMessage>) -> u64 {
-        todo!()
+    fn line_pos(
+        &mut self,
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<u64> {
+        Ok(self.0.table().get(&message)?.line_pos)
     }
 
-    fn offset(&mut self, _self_: Resource<webgpu::GpuCompilationMessage>) -> u64 {
-        todo!()
+    fn offset(
+        &mut self,
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<u64> {
+        Ok(self.0.table().get(&message)?.offset)
     }
 
-    fn length(&mut self, _self_: Resource<webgpu::GpuCompilationMessage>) -> u64 {
-        todo!()
+    fn length(
+        &mut self,
+        message: Resource<webgpu::GpuCompilationMessage>,
+    ) -> wasmtime::Result<u64> {
+        Ok(self.0.table().get(&message)?.length)
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuCompilationMessage>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, message: Resource<webgpu::GpuCompilationMessage>) -> wasmtime::Result<()> {
+        self.0.table().delete(message)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuCompilationInfo for WasiWebGpuImpl<T> {
-    fn drop(&mut self, _rep: Resource<webgpu::GpuCompilationInfo>) -> wasmtime::Result<()> {
-        todo!()
+    fn messages(
+        &mut self,
+        compilation_info: Resource<webgpu::GpuCompilationInfo>,
+    ) -> wasmtime::Result<Vec<Resource<webgpu::GpuCompilationMessage>>> {
+        let messages = self.0.table().get(&compilation_info)?.messages.clone();
+        messages
+            .into_iter()
+            .map(|message| Ok(self.0.table().push(message)?))
+            .collect()
+    }
+
+    fn drop(
+        &mut self,
+        compilation_info: Resource<webgpu::GpuCompilationInfo>,
+    ) -> wasmtime::Result<()> {
+        self.0.table().delete(compilation_info)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuQuerySet for WasiWebGpuImpl<T> {
-    fn destroy(&mut self, _self_: Resource<webgpu::GpuQuerySet>) {
-        todo!()
+    fn destroy(&mut self, query_set: Resource<webgpu::GpuQuerySet>) -> wasmtime::Result<()> {
+        // source: https://www.w3.org/TR/webgpu/#dom-gpuqueryset-destroy
+        // Destroying twice is fine, passes that are still open keep the id until they end.
+        self.0.table().get_mut(&query_set)?.query_set = None;
+        Ok(())
     }
 
-    fn type_(&mut self, _self_: Resource<webgpu::GpuQuerySet>) -> webgpu::GpuQueryType {
-        todo!()
+    fn type_(
+        &mut self,
+        query_set: Resource<webgpu::GpuQuerySet>,
+    ) -> wasmtime::Result<webgpu::GpuQueryType> {
+        Ok(self.0.table().get(&query_set)?.type_)
     }
 
-    fn count(&mut self, _self_: Resource<webgpu::GpuQuerySet>) -> webgpu::GpuSize32Out {
-        todo!()
+    fn count(
+        &mut self,
+        query_set: Resource<webgpu::GpuQuerySet>,
+    ) -> wasmtime::Result<webgpu::GpuSize32Out> {
+        Ok(self.0.table().get(&query_set)?.count)
     }
 
-    fn label(&mut self, _self_: Resource<webgpu::GpuQuerySet>) -> String {
-        todo!()
+    fn label(&mut self, query_set: Resource<webgpu::GpuQuerySet>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&query_set)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuQuerySet>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        query_set: Resource<webgpu::GpuQuerySet>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&query_set)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuQuerySet>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(&mut self, query_set: Resource<webgpu::GpuQuerySet>) -> wasmtime::Result<()> {
+        // The id is released by its handle.
+        self.0.table().delete(query_set)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuRenderBundleEncoder for WasiWebGpuImpl<T> {
     fn finish(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _descriptor: Option<webgpu::GpuRenderBundleDescriptor>,
-    ) -> Resource<webgpu::GpuRenderBundle> {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        descriptor: Option<webgpu::GpuRenderBundleDescriptor>,
+    ) -> wasmtime::Result<Resource<webgpu::GpuRenderBundle>> {
+        let label = descriptor
+            .as_ref()
+            .and_then(|d| d.label.clone())
+            .unwrap_or_default();
+        let render_bundle_encoder = self.0.table().delete(render_bundle_encoder)?;
+        let render_bundle = render_bundle_encoder.error_sink.handle_core_result(
+            self.0
+                .instance()
+                .render_bundle_encoder_finish::<crate::Backend>(
+                    render_bundle_encoder.render_bundle_encoder,
+                    &descriptor
+                        .map(|d| d.to_core(self.0.table()))
+                        .transpose()?
+                        .unwrap_or_default(),
+                    None,
+                ),
+        );
+        Ok(self.0.table().push(RenderBundle {
+            render_bundle,
+            label,
+        })?)
     }
 
-    fn label(&mut self, _self_: Resource<webgpu::GpuRenderBundleEncoder>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&render_bundle_encoder)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuRenderBundleEncoder>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&render_bundle_encoder)?.label = label;
+        Ok(())
     }
 
     fn push_debug_group(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _group_label: String,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        group_label: String,
+    ) -> wasmtime::Result<()> {
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // Labels with interior nul bytes can't be passed to wgpu-core, fall back to an empty label.
+        let group_label = CString::new(group_label).unwrap_or_default();
+        unsafe {
+            wgpu_core::command::bundle_ffi::wgpu_render_bundle_push_debug_group(
+                &mut render_bundle_encoder.render_bundle_encoder,
+                group_label.as_ptr(),
+            )
+        };
+        Ok(())
     }
 
-    fn pop_debug_group(&mut self, _self_: Resource<webgpu::GpuRenderBundleEncoder>) {
-        todo!()
+    fn pop_debug_group(
+        &mut self,
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+    ) -> wasmtime::Result<()> {
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_pop_debug_group(
+            &mut render_bundle_encoder.render_bundle_encoder,
+        );
+        Ok(())
     }
 
     fn insert_debug_marker(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _marker_label: String,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        marker_label: String,
+    ) -> wasmtime::Result<()> {
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        let marker_label = CString::new(marker_label).unwrap_or_default();
+        unsafe {
+            wgpu_core::command::bundle_ffi::wgpu_render_bundle_insert_debug_marker(
+                &mut render_bundle_encoder.render_bundle_encoder,
+                marker_label.as_ptr(),
+            )
+        };
+        Ok(())
     }
 
     fn set_bind_group(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _index: webgpu::GpuIndex32,
-        _bind_group: Resource<webgpu::GpuBindGroup>,
-        _dynamic_offsets: Option<Vec<webgpu::GpuBufferDynamicOffset>>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        index: webgpu::GpuIndex32,
+        bind_group: Resource<webgpu::GpuBindGroup>,
+        dynamic_offsets: Option<Vec<webgpu::GpuBufferDynamicOffset>>,
+    ) -> wasmtime::Result<()> {
+        let bind_group = self.0.table().get(&bind_group)?.bind_group;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#gpubindingcommandsmixin-setbindgroup
+        let dynamic_offsets = dynamic_offsets.unwrap_or_default();
+        unsafe {
+            wgpu_core::command::bundle_ffi::wgpu_render_bundle_set_bind_group(
+                &mut render_bundle_encoder.render_bundle_encoder,
+                index,
+                bind_group,
+                dynamic_offsets.as_ptr(),
+                dynamic_offsets.len(),
+            )
+        };
+        Ok(())
     }
 
     fn set_pipeline(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _pipeline: Resource<wgpu_core::id::RenderPipelineId>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        pipeline: Resource<webgpu::GpuRenderPipeline>,
+    ) -> wasmtime::Result<()> {
+        let pipeline = pipeline.to_core(self.0.table())?;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_set_pipeline(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            pipeline,
+        );
+        Ok(())
     }
 
     fn set_index_buffer(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _buffer: Resource<webgpu::GpuBuffer>,
-        _index_format: webgpu::GpuIndexFormat,
-        _offset: Option<webgpu::GpuSize64>,
-        _size: Option<webgpu::GpuSize64>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        buffer: Resource<webgpu::GpuBuffer>,
+        index_format: webgpu::GpuIndexFormat,
+        offset: Option<webgpu::GpuSize64>,
+        size: Option<webgpu::GpuSize64>,
+    ) -> wasmtime::Result<()> {
+        let buffer_id = self.0.table().get(&buffer)?.buffer;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-setindexbuffer
+        let size = match to_core_conversions::buffer_binding_size("index", size) {
+            Ok(size) => size,
+            Err(error) => {
+                render_bundle_encoder.error_sink.handle_error(error);
+                return Ok(());
+            }
+        };
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_set_index_buffer(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            buffer_id,
+            index_format.into(),
+            offset.unwrap_or(0),
+            size,
+        );
+        Ok(())
     }
 
     fn set_vertex_buffer(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _slot: webgpu::GpuIndex32,
-        _buffer: Resource<webgpu::GpuBuffer>,
-        _offset: Option<webgpu::GpuSize64>,
-        _size: Option<webgpu::GpuSize64>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        slot: webgpu::GpuIndex32,
+        buffer: Resource<webgpu::GpuBuffer>,
+        offset: Option<webgpu::GpuSize64>,
+        size: Option<webgpu::GpuSize64>,
+    ) -> wasmtime::Result<()> {
+        let buffer_id = self.0.table().get(&buffer)?.buffer;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-setvertexbuffer
+        let size = match to_core_conversions::buffer_binding_size("vertex", size) {
+            Ok(size) => size,
+            Err(error) => {
+                render_bundle_encoder.error_sink.handle_error(error);
+                return Ok(());
+            }
+        };
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_set_vertex_buffer(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            slot,
+            buffer_id,
+            offset.unwrap_or(0),
+            size,
+        );
+        Ok(())
     }
 
     fn draw(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _vertex_count: webgpu::GpuSize32,
-        _instance_count: Option<webgpu::GpuSize32>,
-        _first_vertex: Option<webgpu::GpuSize32>,
-        _first_instance: Option<webgpu::GpuSize32>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        vertex_count: webgpu::GpuSize32,
+        instance_count: Option<webgpu::GpuSize32>,
+        first_vertex: Option<webgpu::GpuSize32>,
+        first_instance: Option<webgpu::GpuSize32>,
+    ) -> wasmtime::Result<()> {
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-draw
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_draw(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            vertex_count,
+            instance_count.unwrap_or(1),
+            first_vertex.unwrap_or(0),
+            first_instance.unwrap_or(0),
+        );
+        Ok(())
     }
 
     fn draw_indexed(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _index_count: webgpu::GpuSize32,
-        _instance_count: Option<webgpu::GpuSize32>,
-        _first_index: Option<webgpu::GpuSize32>,
-        _base_vertex: Option<webgpu::GpuSignedOffset32>,
-        _first_instance: Option<webgpu::GpuSize32>,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        index_count: webgpu::GpuSize32,
+        instance_count: Option<webgpu::GpuSize32>,
+        first_index: Option<webgpu::GpuSize32>,
+        base_vertex: Option<webgpu::GpuSignedOffset32>,
+        first_instance: Option<webgpu::GpuSize32>,
+    ) -> wasmtime::Result<()> {
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpurendercommandsmixin-drawindexed
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_draw_indexed(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            index_count,
+            instance_count.unwrap_or(1),
+            first_index.unwrap_or(0),
+            base_vertex.unwrap_or(0),
+            first_instance.unwrap_or(0),
+        );
+        Ok(())
     }
 
     fn draw_indirect(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _indirect_buffer: Resource<webgpu::GpuBuffer>,
-        _indirect_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        indirect_buffer: Resource<webgpu::GpuBuffer>,
+        indirect_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let indirect_buffer = self.0.table().get(&indirect_buffer)?.buffer;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_draw_indirect(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            indirect_buffer,
+            indirect_offset,
+        );
+        Ok(())
     }
 
     fn draw_indexed_indirect(
         &mut self,
-        _self_: Resource<webgpu::GpuRenderBundleEncoder>,
-        _indirect_buffer: Resource<webgpu::GpuBuffer>,
-        _indirect_offset: webgpu::GpuSize64,
-    ) {
-        todo!()
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+        indirect_buffer: Resource<webgpu::GpuBuffer>,
+        indirect_offset: webgpu::GpuSize64,
+    ) -> wasmtime::Result<()> {
+        let indirect_buffer = self.0.table().get(&indirect_buffer)?.buffer;
+        let render_bundle_encoder = self.0.table().get_mut(&render_bundle_encoder)?;
+        wgpu_core::command::bundle_ffi::wgpu_render_bundle_draw_indexed_indirect(
+            &mut render_bundle_encoder.render_bundle_encoder,
+            indirect_buffer,
+            indirect_offset,
+        );
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuRenderBundleEncoder>) -> wasmtime::Result<()> {
-        todo!()
+    fn drop(
+        &mut self,
+        render_bundle_encoder: Resource<webgpu::GpuRenderBundleEncoder>,
+    ) -> wasmtime::Result<()> {
+        self.0.table().delete(render_bundle_encoder)?;
+        Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuComputePipeline for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuComputePipeline>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        compute_pipeline: Resource<webgpu::GpuComputePipeline>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&compute_pipeline)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuComputePipeline>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        compute_pipeline: Resource<webgpu::GpuComputePipeline>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&compute_pipeline)?.label = label;
+        Ok(())
     }
 
     fn get_bind_group_layout(
         &mut self,
         compute_pipeline: Resource<webgpu::GpuComputePipeline>,
         index: u32,
-    ) -> Resource<webgpu::GpuBindGroupLayout> {
-        let pipeline_id = *self.0.table().get(&compute_pipeline).unwrap();
-        let bind_group_layout = core_result(
+    ) -> wasmtime::Result<Resource<webgpu::GpuBindGroupLayout>> {
+        let compute_pipeline = self.0.table().get(&compute_pipeline)?;
+        let pipeline_id = compute_pipeline.compute_pipeline;
+        let error_sink = compute_pipeline.error_sink.clone();
+        let bind_group_layout = error_sink.handle_core_result(
             self.0
                 .instance()
                 .compute_pipeline_get_bind_group_layout::<crate::Backend>(pipeline_id, index, None),
-        )
-        .unwrap();
-        self.0.table().push(bind_group_layout).unwrap()
+        );
+        Ok(self.0.table().push(BindGroupLayout {
+            bind_group_layout,
+            label: String::new(),
+        })?)
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuComputePipeline>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(
+        &mut self,
+        compute_pipeline: Resource<webgpu::GpuComputePipeline>,
+    ) -> wasmtime::Result<()> {
+        let compute_pipeline = self.0.table().delete(compute_pipeline)?;
+        self.0
+            .instance()
+            .compute_pipeline_drop::<crate::Backend>(compute_pipeline.compute_pipeline);
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuBindGroup for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuBindGroup>) -> String {
-        todo!()
+    fn label(&mut self, bind_group: Resource<webgpu::GpuBindGroup>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&bind_group)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuBindGroup>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        bind_group: Resource<webgpu::GpuBindGroup>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&bind_group)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuBindGroup>) -> wasmtime::Result<()> {
+    fn drop(&mut self, bind_group: Resource<webgpu::GpuBindGroup>) -> wasmtime::Result<()> {
+        let bind_group = self.0.table().delete(bind_group)?;
+        self.0
+            .instance()
+            .bind_group_drop::<crate::Backend>(bind_group.bind_group);
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuPipelineLayout for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuPipelineLayout>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        pipeline_layout: Resource<webgpu::GpuPipelineLayout>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&pipeline_layout)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuPipelineLayout>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        pipeline_layout: Resource<webgpu::GpuPipelineLayout>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&pipeline_layout)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuPipelineLayout>) -> wasmtime::Result<()> {
+    fn drop(
+        &mut self,
+        pipeline_layout: Resource<webgpu::GpuPipelineLayout>,
+    ) -> wasmtime::Result<()> {
+        let pipeline_layout = self.0.table().delete(pipeline_layout)?;
+        self.0
+            .instance()
+            .pipeline_layout_drop::<crate::Backend>(pipeline_layout.pipeline_layout);
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuBindGroupLayout for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuBindGroupLayout>) -> String {
-        todo!()
+    fn label(
+        &mut self,
+        bind_group_layout: Resource<webgpu::GpuBindGroupLayout>,
+    ) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&bind_group_layout)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuBindGroupLayout>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        bind_group_layout: Resource<webgpu::GpuBindGroupLayout>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&bind_group_layout)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuBindGroupLayout>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(
+        &mut self,
+        bind_group_layout: Resource<webgpu::GpuBindGroupLayout>,
+    ) -> wasmtime::Result<()> {
+        let bind_group_layout = self.0.table().delete(bind_group_layout)?;
+        self.0
+            .instance()
+            .bind_group_layout_drop::<crate::Backend>(bind_group_layout.bind_group_layout);
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuExternalTexture for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuExternalTexture>) -> String {
-        todo!()
+    fn label(&mut self, _self_: Resource<webgpu::GpuExternalTexture>) -> wasmtime::Result<String> {
+        Err(error::not_implemented("gpu-external-texture.label"))
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuExternalTexture>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        _self_: Resource<webgpu::GpuExternalTexture>,
+        _label: String,
+    ) -> wasmtime::Result<()> {
+        Err(error::not_implemented("gpu-external-texture.set-label"))
     }
 
     fn drop(&mut self, _rep: Resource<webgpu::GpuExternalTexture>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("gpu-external-texture.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuSampler for WasiWebGpuImpl<T> {
-    fn label(&mut self, _self_: Resource<webgpu::GpuSampler>) -> String {
-        todo!()
+    fn label(&mut self, sampler: Resource<webgpu::GpuSampler>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&sampler)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuSampler>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        sampler: Resource<webgpu::GpuSampler>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&sampler)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuSampler>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(&mut self, sampler: Resource<webgpu::GpuSampler>) -> wasmtime::Result<()> {
+        let sampler = self.0.table().delete(sampler)?;
+        self.0
+            .instance()
+            .sampler_drop::<crate::Backend>(sampler.sampler);
         Ok(())
     }
 }
 
 #[async_trait::async_trait]
 impl<T: WasiWebGpuView> webgpu::HostGpuBuffer for WasiWebGpuImpl<T> {
-    fn size(&mut self, _self_: Resource<webgpu::GpuBuffer>) -> webgpu::GpuSize64Out {
-        todo!()
+    fn size(
+        &mut self,
+        buffer: Resource<webgpu::GpuBuffer>,
+    ) -> wasmtime::Result<webgpu::GpuSize64Out> {
+        Ok(self.0.table().get(&buffer)?.size)
     }
 
-    fn usage(&mut self, _self_: Resource<webgpu::GpuBuffer>) -> webgpu::GpuFlagsConstant {
-        todo!()
+    fn usage(
+        &mut self,
+        buffer: Resource<webgpu::GpuBuffer>,
+    ) -> wasmtime::Result<webgpu::GpuFlagsConstant> {
+        Ok(self.0.table().get(&buffer)?.usage.bits())
     }
 
-    fn map_state(&mut self, _self_: Resource<webgpu::GpuBuffer>) -> webgpu::GpuBufferMapState {
-        todo!()
+    fn map_state(
+        &mut self,
+        buffer: Resource<webgpu::GpuBuffer>,
+    ) -> wasmtime::Result<webgpu::GpuBufferMapState> {
+        Ok(self.0.table().get(&buffer)?.map_state.lock().unwrap().state)
     }
 
     async fn map_async(
@@ -1962,19 +3658,46 @@ impl<T: WasiWebGpuView> webgpu::HostGpuBuffer for WasiWebGpuImpl<T> {
         mode: webgpu::GpuMapModeFlags,
         offset: Option<webgpu::GpuSize64>,
         size: Option<webgpu::GpuSize64>,
-    ) {
-        let buffer = self.0.table().get(&buffer).unwrap().buffer;
+    ) -> wasmtime::Result<()> {
+        let host_buffer = self.0.table().get(&buffer)?;
+        let buffer_id = host_buffer.buffer;
+        let poller = host_buffer.poller.clone();
+        let error_sink = host_buffer.error_sink.clone();
+        let map_state = Arc::clone(&host_buffer.map_state);
+
+        // source: https://www.w3.org/TR/webgpu/#dom-gpubuffer-mapasync
+        let state = map_state.lock().unwrap().state;
+        match state {
+            webgpu::GpuBufferMapState::Unmapped => {}
+            webgpu::GpuBufferMapState::Pending => {
+                error_sink.handle_error(wgpu_core::resource::BufferAccessError::MapAlreadyPending);
+                return Ok(());
+            }
+            webgpu::GpuBufferMapState::Mapped => {
+                error_sink.handle_error(wgpu_core::resource::BufferAccessError::AlreadyMapped);
+                return Ok(());
+            }
+        }
+        // source: https://www.w3.org/TR/webgpu/#typedefdef-gpumapmodeflags
+        let host = match mode {
+            0x0001 => wgpu_core::device::HostMap::Read,
+            0x0002 => wgpu_core::device::HostMap::Write,
+            _ => {
+                error_sink.handle_error(ValidationError(format!(
+                    "Map mode {mode:#x} must be exactly one of READ or WRITE"
+                )));
+                return Ok(());
+            }
+        };
+        // source: https://www.w3.org/TR/webgpu/#dom-gpubuffer-mapasync
+        let offset = offset.unwrap_or(0);
+        map_state.lock().unwrap().state = webgpu::GpuBufferMapState::Pending;
+
         let instance = self.0.instance();
-        CallbackFuture::new(Box::new(
+        let result = CallbackFuture::new(Box::new(
             move |resolve: Box<
                 dyn FnOnce(Box<Result<(), wgpu_core::resource::BufferAccessError>>) + Send,
             >| {
-                // source: https://www.w3.org/TR/webgpu/#typedefdef-gpumapmodeflags
-                let host = match mode {
-                    0 => wgpu_core::device::HostMap::Read,
-                    1 => wgpu_core::device::HostMap::Write,
-                    _ => panic!(),
-                };
                 let op = wgpu_core::resource::BufferMapOperation {
                     host,
                     callback: Some(wgpu_core::resource::BufferMapCallback::from_rust(Box::new(
@@ -1984,16 +3707,25 @@ impl<T: WasiWebGpuView> webgpu::HostGpuBuffer for WasiWebGpuImpl<T> {
                     ))),
                 };
 
-                let offset = offset.unwrap();
-                instance
-                    .buffer_map_async::<crate::Backend>(buffer, offset, size, op)
-                    .unwrap();
-                // TODO: only poll this device.
-                instance.poll_all_devices(true).unwrap();
+                // On failure, wgpu-core has already called the callback with the error.
+                let _ = instance.buffer_map_async::<crate::Backend>(buffer_id, offset, size, op);
+                // Resolves once the device's poller fires the map callback.
+                poller.poll();
             },
         ))
-        .await
-        .unwrap();
+        .await;
+        let mapped = error_sink.handle_result(*result).is_some();
+
+        let mut map_state = map_state.lock().unwrap();
+        // Unmapping or destroying the buffer or its device while pending aborts the mapping.
+        if map_state.state == webgpu::GpuBufferMapState::Pending {
+            map_state.state = if mapped {
+                webgpu::GpuBufferMapState::Mapped
+            } else {
+                webgpu::GpuBufferMapState::Unmapped
+            };
+        };
+        Ok(())
     }
 
     fn get_mapped_range(
@@ -2001,43 +3733,89 @@ impl<T: WasiWebGpuView> webgpu::HostGpuBuffer for WasiWebGpuImpl<T> {
         buffer: Resource<webgpu::GpuBuffer>,
         offset: Option<webgpu::GpuSize64>,
         size: Option<webgpu::GpuSize64>,
-    ) -> Resource<webgpu::GpuBuffer> {
-        let buffer_rep = buffer.rep();
-        let buffer_id = self.0.table().get(&buffer).unwrap().buffer;
-        let (ptr, len) = self
-            .0
-            .instance()
-            .buffer_get_mapped_range::<crate::Backend>(buffer_id, offset.unwrap_or(0), size)
-            .unwrap();
-        let remote_buffer = BufferPtr { ptr, len };
-        let buffer = self.0.table().get_mut(&buffer).unwrap();
-        buffer.mapped = Some(remote_buffer);
-        Resource::new_own(buffer_rep)
+    ) -> wasmtime::Result<Resource<webgpu::RemoteBuffer>> {
+        let host_buffer = self.0.table().get(&buffer)?;
+        let buffer_id = host_buffer.buffer;
+        let error_sink = host_buffer.error_sink.clone();
+        let mapped =
+            error_sink.handle_result(self.0.instance().buffer_get_mapped_range::<crate::Backend>(
+                buffer_id,
+                offset.unwrap_or(0),
+                size,
+            ));
+        // Only ranges that got memory need a token for unmap to detach them.
+        let mapping = match mapped {
+            Some(_) => Arc::downgrade(
+                self.0
+                    .table()
+                    .get(&buffer)?
+                    .map_state
+                    .lock()
+                    .unwrap()
+                    .mapping
+                    .get_or_insert_with(Default::default),
+            ),
+            None => Weak::new(),
+        };
+        let remote_buffer = RemoteBuffer {
+            mapped: mapped.map(|(ptr, len)| BufferPtr { ptr, len }),
+            mapping,
+            error_sink,
+        };
+        Ok(self.0.table().push(remote_buffer)?)
     }
 
-    fn unmap(&mut self, buffer: Resource<webgpu::GpuBuffer>) {
-        let buffer = self.0.table().get_mut(&buffer).unwrap();
-        buffer.mapped.take().unwrap();
+    fn unmap(&mut self, buffer: Resource<webgpu::GpuBuffer>) -> wasmtime::Result<()> {
+        let buffer = self.0.table().get(&buffer)?;
+        let mut map_state = buffer.map_state.lock().unwrap();
+        if map_state.state == webgpu::GpuBufferMapState::Unmapped {
+            buffer
+                .error_sink
+                .handle_error(wgpu_core::resource::BufferAccessError::NotMapped);
+            return Ok(());
+        }
+        map_state.unmap();
+        drop(map_state);
         let buffer_id = buffer.buffer;
-        self.0
-            .instance()
-            .buffer_unmap::<crate::Backend>(buffer_id)
-            .unwrap();
+        let error_sink = buffer.error_sink.clone();
+        error_sink.handle_result(self.0.instance().buffer_unmap::<crate::Backend>(buffer_id));
+        Ok(())
     }
 
-    fn destroy(&mut self, _self_: Resource<webgpu::GpuBuffer>) {
-        todo!()
+    fn destroy(&mut self, buffer: Resource<webgpu::GpuBuffer>) -> wasmtime::Result<()> {
+        let buffer = self.0.table().get(&buffer)?;
+        // source: https://www.w3.org/TR/webgpu/#dom-gpubuffer-destroy
+        // Destroying implicitly unmaps, wgpu-core takes care of the mapping itself.
+        buffer.map_state.lock().unwrap().unmap();
+        let buffer_id = buffer.buffer;
+        let error_sink = buffer.error_sink.clone();
+        error_sink.handle_result(
+            self.0
+                .instance()
+                .buffer_destroy::<crate::Backend>(buffer_id),
+        );
+        Ok(())
     }
 
-    fn label(&mut self, _self_: Resource<webgpu::GpuBuffer>) -> String {
-        todo!()
+    fn label(&mut self, buffer: Resource<webgpu::GpuBuffer>) -> wasmtime::Result<String> {
+        Ok(self.0.table().get(&buffer)?.label.clone())
     }
 
-    fn set_label(&mut self, _self_: Resource<webgpu::GpuBuffer>, _label: String) {
-        todo!()
+    fn set_label(
+        &mut self,
+        buffer: Resource<webgpu::GpuBuffer>,
+        label: String,
+    ) -> wasmtime::Result<()> {
+        self.0.table().get_mut(&buffer)?.label = label;
+        Ok(())
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuBuffer>) -> wasmtime::Result<()> {
+    fn drop(&mut self, buffer: Resource<webgpu::GpuBuffer>) -> wasmtime::Result<()> {
+        // Remote buffers from `get-mapped-range` see the mapping go away with it.
+        let buffer = self.0.table().delete(buffer)?;
+        self.0
+            .instance()
+            .buffer_drop::<crate::Backend>(buffer.buffer, false);
         Ok(())
     }
 }
@@ -2045,31 +3823,38 @@ impl<T: WasiWebGpuView> webgpu::HostGpu for WasiWebGpuImpl<T> {
     fn request_adapter(
         &mut self,
         _self_: Resource<webgpu::Gpu>,
-        _options: Option<webgpu::GpuRequestAdapterOptions>,
-    ) -> Resource<wgpu_core::id::AdapterId> {
-        let adapter = self
-            .0
-            .instance()
-            .request_adapter(
-                &Default::default(),
-                wgpu_core::instance::AdapterInputs::Mask(wgpu_types::Backends::all(), |_| None),
-            )
-            .unwrap();
-        self.0.table().push(adapter).unwrap()
+        options: Option<webgpu::GpuRequestAdapterOptions>,
+    ) -> wasmtime::Result<Option<Resource<webgpu::GpuAdapter>>> {
+        let options = options
+            .map(|options| options.to_core(self.0.table()))
+            .transpose()?
+            .unwrap_or_default();
+        // source: https://www.w3.org/TR/webgpu/#dom-gpu-requestadapter
+        // Not finding a matching adapter isn't an error, it resolves to null.
+        let Ok(adapter) = self.0.instance().request_adapter(
+            &options,
+            wgpu_core::instance::AdapterInputs::Mask(wgpu_types::Backends::all(), |_| None),
+        ) else {
+            return Ok(None);
+        };
+        let adapter = AdapterHandle::new(self.0.instance(), adapter);
+        Ok(Some(self.0.table().push(Adapter { adapter })?))
     }
 
     fn get_preferred_canvas_format(
         &mut self,
         _self_: Resource<webgpu::Gpu>,
-    ) -> webgpu::GpuTextureFormat {
-        todo!()
+    ) -> wasmtime::Result<webgpu::GpuTextureFormat> {
+        // source: https://www.w3.org/TR/webgpu/#dom-gpu-getpreferredcanvasformat
+        // Has to be one of `rgba8unorm` or `bgra8unorm`, and the latter is what most surfaces prefer.
+        Ok(webgpu::GpuTextureFormat::Bgra8unorm)
     }
 
     fn wgsl_language_features(
         &mut self,
         _self_: Resource<webgpu::Gpu>,
-    ) -> Resource<webgpu::WgslLanguageFeatures> {
-        todo!()
+    ) -> wasmtime::Result<Resource<webgpu::WgslLanguageFeatures>> {
+        Err(error::not_implemented("gpu.wgsl-language-features"))
     }
 
     fn drop(&mut self, _rep: Resource<webgpu::Gpu>) -> wasmtime::Result<()> {
@@ -2077,382 +3862,396 @@ impl<T: WasiWebGpuView> webgpu::HostGpu for WasiWebGpuImpl<T> {
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuAdapterInfo for WasiWebGpuImpl<T> {
-    fn vendor(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> String {
-        todo!()
+    fn vendor(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> wasmtime::Result<String> {
+        Err(error::not_implemented("gpu-adapter-info.vendor"))
     }
 
-    fn architecture(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> String {
-        todo!()
+    fn architecture(
+        &mut self,
+        _self_: Resource<webgpu::GpuAdapterInfo>,
+    ) -> wasmtime::Result<String> {
+        Err(error::not_implemented("gpu-adapter-info.architecture"))
     }
 
-    fn device(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> String {
-        todo!()
+    fn device(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> wasmtime::Result<String> {
+        Err(error::not_implemented("gpu-adapter-info.device"))
     }
 
-    fn description(&mut self, _self_: Resource<webgpu::GpuAdapterInfo>) -> String {
-        todo!()
+    fn description(
+        &mut self,
+        _self_: Resource<webgpu::GpuAdapterInfo>,
+    ) -> wasmtime::Result<String> {
+        Err(error::not_implemented("gpu-adapter-info.description"))
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuAdapterInfo>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(&mut self, info: Resource<webgpu::GpuAdapterInfo>) -> wasmtime::Result<()> {
+        self.0.table().delete(info)?;
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostWgslLanguageFeatures for WasiWebGpuImpl<T> {
-    fn has(&mut self, _self_: Resource<webgpu::WgslLanguageFeatures>, _key: String) -> bool {
-        todo!()
+    fn has(
+        &mut self,
+        _self_: Resource<webgpu::WgslLanguageFeatures>,
+        _key: String,
+    ) -> wasmtime::Result<bool> {
+        Err(error::not_implemented("wgsl-language-features.has"))
     }
 
     fn drop(&mut self, _rep: Resource<webgpu::WgslLanguageFeatures>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("wgsl-language-features.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuSupportedFeatures for WasiWebGpuImpl<T> {
-    fn has(&mut self, features: Resource<webgpu::GpuSupportedFeatures>, query: String) -> bool {
-        let features = self.0.table().get(&features).unwrap();
-        match query.as_str() {
-            "depth-clip-control" => features.contains(wgpu_types::Features::DEPTH_CLIP_CONTROL),
-            "timestamp-query" => features.contains(wgpu_types::Features::TIMESTAMP_QUERY),
-            "indirect-first-instance" => {
-                features.contains(wgpu_types::Features::INDIRECT_FIRST_INSTANCE)
-            }
-            "shader-f16" => features.contains(wgpu_types::Features::SHADER_F16),
-            "depth32float-stencil8" => {
-                features.contains(wgpu_types::Features::DEPTH32FLOAT_STENCIL8)
-            }
-            "texture-compression-bc" => {
-                features.contains(wgpu_types::Features::TEXTURE_COMPRESSION_BC)
-            }
-            "texture-compression-etc2" => {
-                features.contains(wgpu_types::Features::TEXTURE_COMPRESSION_ETC2)
-            }
-            "texture-compression-astc" => {
-                features.contains(wgpu_types::Features::TEXTURE_COMPRESSION_ASTC)
-            }
-            "rg11b10ufloat-renderable" => {
-                features.contains(wgpu_types::Features::RG11B10UFLOAT_RENDERABLE)
-            }
-            "bgra8unorm-storage" => features.contains(wgpu_types::Features::BGRA8UNORM_STORAGE),
-            // "float32-filterable" => features.contains(wgpu_types::Features::FLOAT32_FILTERABLE),
-            _ => todo!(),
-        }
-    }
-
-    fn drop(&mut self, _rep: Resource<webgpu::GpuSupportedFeatures>) -> wasmtime::Result<()> {
+    fn has(
+        &mut self,
+        features: Resource<webgpu::GpuSupportedFeatures>,
+        query: String,
+    ) -> wasmtime::Result<bool> {
+        let features = *self.0.table().get(&features)?;
+        // Names that aren't spec features, or that we don't know about, just aren't in the set.
+        Ok(enum_conversions::FEATURE_NAMES
+            .iter()
+            .find(|(_, name)| *name == query)
+            .is_some_and(|(feature, _)| features.contains((*feature).into())))
+    }
+
+    fn values(
+        &mut self,
+        features: Resource<webgpu::GpuSupportedFeatures>,
+    ) -> wasmtime::Result<Vec<String>> {
+        let features = *self.0.table().get(&features)?;
+        Ok(enum_conversions::FEATURE_NAMES
+            .iter()
+            .filter(|(feature, _)| features.contains((*feature).into()))
+            .map(|(_, name)| name.to_string())
+            .collect())
+    }
+
+    fn drop(&mut self, features: Resource<webgpu::GpuSupportedFeatures>) -> wasmtime::Result<()> {
+        self.0.table().delete(features)?;
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostGpuSupportedLimits for WasiWebGpuImpl<T> {
-    fn max_texture_dimension1_d(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_texture_dimension_1d
+    fn max_texture_dimension1_d(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_texture_dimension_1d)
     }
 
-    fn max_texture_dimension2_d(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_texture_dimension_2d
+    fn max_texture_dimension2_d(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_texture_dimension_2d)
     }
 
-    fn max_texture_dimension3_d(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_texture_dimension_3d
+    fn max_texture_dimension3_d(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_texture_dimension_3d)
     }
 
-    fn max_texture_array_layers(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_texture_array_layers
+    fn max_texture_array_layers(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_texture_array_layers)
     }
 
-    fn max_bind_groups(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_bind_groups
+    fn max_bind_groups(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_bind_groups)
     }
 
     fn max_bind_groups_plus_vertex_buffers(
         &mut self,
-        _limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        todo!()
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits::max_bind_groups_plus_vertex_buffers(limits))
     }
 
-    fn max_bindings_per_bind_group(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_bindings_per_bind_group
+    fn max_bindings_per_bind_group(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_bindings_per_bind_group)
     }
 
     fn max_dynamic_uniform_buffers_per_pipeline_layout(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_dynamic_uniform_buffers_per_pipeline_layout
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_dynamic_uniform_buffers_per_pipeline_layout)
     }
 
     fn max_dynamic_storage_buffers_per_pipeline_layout(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_dynamic_storage_buffers_per_pipeline_layout
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_dynamic_storage_buffers_per_pipeline_layout)
     }
 
     fn max_sampled_textures_per_shader_stage(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_sampled_textures_per_shader_stage
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_sampled_textures_per_shader_stage)
     }
 
     fn max_samplers_per_shader_stage(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_samplers_per_shader_stage
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_samplers_per_shader_stage)
     }
 
     fn max_storage_buffers_per_shader_stage(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_storage_buffers_per_shader_stage
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_storage_buffers_per_shader_stage)
     }
 
     fn max_storage_textures_per_shader_stage(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_storage_textures_per_shader_stage
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_storage_textures_per_shader_stage)
     }
 
     fn max_uniform_buffers_per_shader_stage(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_uniform_buffers_per_shader_stage
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_uniform_buffers_per_shader_stage)
     }
 
     fn max_uniform_buffer_binding_size(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u64 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_uniform_buffer_binding_size as u64
+    ) -> wasmtime::Result<u64> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_uniform_buffer_binding_size as u64)
     }
 
     fn max_storage_buffer_binding_size(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u64 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_storage_buffer_binding_size as u64
+    ) -> wasmtime::Result<u64> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_storage_buffer_binding_size as u64)
     }
 
     fn min_uniform_buffer_offset_alignment(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.min_uniform_buffer_offset_alignment
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.min_uniform_buffer_offset_alignment)
     }
 
     fn min_storage_buffer_offset_alignment(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.min_storage_buffer_offset_alignment
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.min_storage_buffer_offset_alignment)
     }
 
-    fn max_vertex_buffers(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_vertex_buffers
+    fn max_vertex_buffers(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_vertex_buffers)
     }
 
-    fn max_buffer_size(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u64 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_buffer_size
+    fn max_buffer_size(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u64> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_buffer_size)
     }
 
-    fn max_vertex_attributes(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_vertex_attributes
+    fn max_vertex_attributes(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_vertex_attributes)
     }
 
     fn max_vertex_buffer_array_stride(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_vertex_buffer_array_stride
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_vertex_buffer_array_stride)
     }
 
     fn max_inter_stage_shader_components(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_inter_stage_shader_components
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_inter_stage_shader_components)
     }
 
     fn max_inter_stage_shader_variables(
         &mut self,
-        _limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        todo!()
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits::max_inter_stage_shader_variables(limits))
     }
 
-    fn max_color_attachments(&mut self, _limits: Resource<webgpu::GpuSupportedLimits>) -> u32 {
-        todo!()
+    fn max_color_attachments(
+        &mut self,
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_color_attachments)
     }
 
     fn max_color_attachment_bytes_per_sample(
         &mut self,
-        _limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        todo!()
+        limits: Resource<webgpu::GpuSupportedLimits>,
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_color_attachment_bytes_per_sample)
     }
 
     fn max_compute_workgroup_storage_size(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_workgroup_storage_size
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_workgroup_storage_size)
     }
 
     fn max_compute_invocations_per_workgroup(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_invocations_per_workgroup
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_invocations_per_workgroup)
     }
 
     fn max_compute_workgroup_size_x(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_workgroup_size_x
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_workgroup_size_x)
     }
 
     fn max_compute_workgroup_size_y(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_workgroup_size_y
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_workgroup_size_y)
     }
 
     fn max_compute_workgroup_size_z(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_workgroup_size_z
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_workgroup_size_z)
     }
 
     fn max_compute_workgroups_per_dimension(
         &mut self,
         limits: Resource<webgpu::GpuSupportedLimits>,
-    ) -> u32 {
-        let limits = self.0.table().get(&limits).unwrap();
-        limits.max_compute_workgroups_per_dimension
+    ) -> wasmtime::Result<u32> {
+        let limits = self.0.table().get(&limits)?;
+        Ok(limits.max_compute_workgroups_per_dimension)
     }
 
-    fn drop(&mut self, _rep: Resource<webgpu::GpuSupportedLimits>) -> wasmtime::Result<()> {
-        // TODO:
+    fn drop(&mut self, limits: Resource<webgpu::GpuSupportedLimits>) -> wasmtime::Result<()> {
+        self.0.table().delete(limits)?;
         Ok(())
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostAllowSharedBufferSource for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::AllowSharedBufferSource>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("allow-shared-buffer-source.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostPredefinedColorSpace for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::PredefinedColorSpace>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("predefined-color-space.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostEventHandler for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::EventHandler>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("event-handler.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostOffscreenCanvas for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::OffscreenCanvas>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("offscreen-canvas.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostHtmlCanvasElement for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::HtmlCanvasElement>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("html-canvas-element.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostVideoFrame for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::VideoFrame>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("video-frame.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostHtmlVideoElement for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::HtmlVideoElement>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("html-video-element.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostHtmlImageElement for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::HtmlImageElement>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("html-image-element.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostImageData for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::ImageData>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("image-data.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostImageBitmap for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::ImageBitmap>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("image-bitmap.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostArrayBuffer for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::ArrayBuffer>) -> wasmtime::Result<()> {
-        todo!()
+        Err(error::not_implemented("array-buffer.drop"))
     }
 }
 impl<T: WasiWebGpuView> webgpu::HostUint32Array for WasiWebGpuImpl<T> {
     fn drop(&mut self, _rep: Resource<webgpu::Uint32Array>) -> wasmtime::Result<()> {
-        todo!()
-    }
-}
-
-fn core_result<I, E>(
-    (id, error): (wgpu_core::id::Id<I>, Option<E>),
-) -> Result<wgpu_core::id::Id<I>, E>
-where
-    I: wgpu_core::id::Marker,
-{
-    match error {
-        Some(error) => Err(error),
-        None => Ok(id),
-    }
-}
-
-// same as core_result, but handles tuple of two ids for Ok.
-fn core_results_2<I1, I2, E>(
-    (a, b, error): (wgpu_core::id::Id<I1>, wgpu_core::id::Id<I2>, Option<E>),
-) -> Result<(wgpu_core::id::Id<I1>, wgpu_core::id::Id<I2>), E>
-where
-    I1: wgpu_core::id::Marker,
-    I2: wgpu_core::id::Marker,
-{
-    match error {
-        Some(error) => Err(error),
-        None => Ok((a, b)),
+        Err(error::not_implemented("uint32-array.drop"))
     }
 }
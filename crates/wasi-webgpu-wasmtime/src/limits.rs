@@ -0,0 +1,278 @@
+use crate::wasi::webgpu::webgpu;
+
+/// wgpu doesn't have this limit, it only enforces bind groups and vertex buffers separately.
+pub(crate) fn max_bind_groups_plus_vertex_buffers(limits: &wgpu_types::Limits) -> u32 {
+    limits.max_bind_groups + limits.max_vertex_buffers
+}
+
+/// wgpu doesn't have this limit either. Each variable takes up to 4 components,
+/// and the 4 components of the position builtin don't count towards the components limit.
+pub(crate) fn max_inter_stage_shader_variables(limits: &wgpu_types::Limits) -> u32 {
+    (limits.max_inter_stage_shader_components + 4) / 4
+}
+
+/// Builds the limits a new device gets from the guest's `required-limits`,
+/// rejecting any limit the adapter can't provide.
+// source: https://www.w3.org/TR/webgpu/#dom-gpuadapter-requestdevice
+pub(crate) fn required_limits(
+    required: &[(String, u64)],
+    adapter: &wgpu_types::Limits,
+) -> Result<wgpu_types::Limits, webgpu::GpuRequestDeviceError> {
+    let mut limits = wgpu_types::Limits::default();
+    for (name, value) in required {
+        let (name, value) = (name.as_str(), *value);
+        let l = &mut limits;
+        match name {
+            "maxTextureDimension1D" => maximum(
+                name,
+                value,
+                &mut l.max_texture_dimension_1d,
+                adapter.max_texture_dimension_1d,
+            ),
+            "maxTextureDimension2D" => maximum(
+                name,
+                value,
+                &mut l.max_texture_dimension_2d,
+                adapter.max_texture_dimension_2d,
+            ),
+            "maxTextureDimension3D" => maximum(
+                name,
+                value,
+                &mut l.max_texture_dimension_3d,
+                adapter.max_texture_dimension_3d,
+            ),
+            "maxTextureArrayLayers" => maximum(
+                name,
+                value,
+                &mut l.max_texture_array_layers,
+                adapter.max_texture_array_layers,
+            ),
+            "maxBindGroups" => {
+                maximum(name, value, &mut l.max_bind_groups, adapter.max_bind_groups)
+            }
+            "maxBindGroupsPlusVertexBuffers" => {
+                check_maximum(name, value, max_bind_groups_plus_vertex_buffers(adapter))
+            }
+            "maxBindingsPerBindGroup" => maximum(
+                name,
+                value,
+                &mut l.max_bindings_per_bind_group,
+                adapter.max_bindings_per_bind_group,
+            ),
+            "maxDynamicUniformBuffersPerPipelineLayout" => maximum(
+                name,
+                value,
+                &mut l.max_dynamic_uniform_buffers_per_pipeline_layout,
+                adapter.max_dynamic_uniform_buffers_per_pipeline_layout,
+            ),
+            "maxDynamicStorageBuffersPerPipelineLayout" => maximum(
+                name,
+                value,
+                &mut l.max_dynamic_storage_buffers_per_pipeline_layout,
+                adapter.max_dynamic_storage_buffers_per_pipeline_layout,
+            ),
+            "maxSampledTexturesPerShaderStage" => maximum(
+                name,
+                value,
+                &mut l.max_sampled_textures_per_shader_stage,
+                adapter.max_sampled_textures_per_shader_stage,
+            ),
+            "maxSamplersPerShaderStage" => maximum(
+                name,
+                value,
+                &mut l.max_samplers_per_shader_stage,
+                adapter.max_samplers_per_shader_stage,
+            ),
+            "maxStorageBuffersPerShaderStage" => maximum(
+                name,
+                value,
+                &mut l.max_storage_buffers_per_shader_stage,
+                adapter.max_storage_buffers_per_shader_stage,
+            ),
+            "maxStorageTexturesPerShaderStage" => maximum(
+                name,
+                value,
+                &mut l.max_storage_textures_per_shader_stage,
+                adapter.max_storage_textures_per_shader_stage,
+            ),
+            "maxUniformBuffersPerShaderStage" => maximum(
+                name,
+                value,
+                &mut l.max_uniform_buffers_per_shader_stage,
+                adapter.max_uniform_buffers_per_shader_stage,
+            ),
+            "maxUniformBufferBindingSize" => maximum(
+                name,
+                value,
+                &mut l.max_uniform_buffer_binding_size,
+                adapter.max_uniform_buffer_binding_size,
+            ),
+            "maxStorageBufferBindingSize" => maximum(
+                name,
+                value,
+                &mut l.max_storage_buffer_binding_size,
+                adapter.max_storage_buffer_binding_size,
+            ),
+            "minUniformBufferOffsetAlignment" => alignment(
+                name,
+                value,
+                &mut l.min_uniform_buffer_offset_alignment,
+                adapter.min_uniform_buffer_offset_alignment,
+            ),
+            "minStorageBufferOffsetAlignment" => alignment(
+                name,
+                value,
+                &mut l.min_storage_buffer_offset_alignment,
+                adapter.min_storage_buffer_offset_alignment,
+            ),
+            "maxVertexBuffers" => maximum(
+                name,
+                value,
+                &mut l.max_vertex_buffers,
+                adapter.max_vertex_buffers,
+            ),
+            "maxBufferSize" => {
+                maximum(name, value, &mut l.max_buffer_size, adapter.max_buffer_size)
+            }
+            "maxVertexAttributes" => maximum(
+                name,
+                value,
+                &mut l.max_vertex_attributes,
+                adapter.max_vertex_attributes,
+            ),
+            "maxVertexBufferArrayStride" => maximum(
+                name,
+                value,
+                &mut l.max_vertex_buffer_array_stride,
+                adapter.max_vertex_buffer_array_stride,
+            ),
+            "maxInterStageShaderComponents" => maximum(
+                name,
+                value,
+                &mut l.max_inter_stage_shader_components,
+                adapter.max_inter_stage_shader_components,
+            ),
+            "maxInterStageShaderVariables" => {
+                check_maximum(name, value, max_inter_stage_shader_variables(adapter))
+            }
+            "maxColorAttachments" => maximum(
+                name,
+                value,
+                &mut l.max_color_attachments,
+                adapter.max_color_attachments,
+            ),
+            "maxColorAttachmentBytesPerSample" => maximum(
+                name,
+                value,
+                &mut l.max_color_attachment_bytes_per_sample,
+                adapter.max_color_attachment_bytes_per_sample,
+            ),
+            "maxComputeWorkgroupStorageSize" => maximum(
+                name,
+                value,
+                &mut l.max_compute_workgroup_storage_size,
+                adapter.max_compute_workgroup_storage_size,
+            ),
+            "maxComputeInvocationsPerWorkgroup" => maximum(
+                name,
+                value,
+                &mut l.max_compute_invocations_per_workgroup,
+                adapter.max_compute_invocations_per_workgroup,
+            ),
+            "maxComputeWorkgroupSizeX" => maximum(
+                name,
+                value,
+                &mut l.max_compute_workgroup_size_x,
+                adapter.max_compute_workgroup_size_x,
+            ),
+            "maxComputeWorkgroupSizeY" => maximum(
+                name,
+                value,
+                &mut l.max_compute_workgroup_size_y,
+                adapter.max_compute_workgroup_size_y,
+            ),
+            "maxComputeWorkgroupSizeZ" => maximum(
+                name,
+                value,
+                &mut l.max_compute_workgroup_size_z,
+                adapter.max_compute_workgroup_size_z,
+            ),
+            "maxComputeWorkgroupsPerDimension" => maximum(
+                name,
+                value,
+                &mut l.max_compute_workgroups_per_dimension,
+                adapter.max_compute_workgroups_per_dimension,
+            ),
+            _ => Err(operation_error(format!("Unknown limit: {name}"))),
+        }?;
+    }
+    Ok(limits)
+}
+
+fn operation_error(message: String) -> webgpu::GpuRequestDeviceError {
+    webgpu::GpuRequestDeviceError {
+        kind: webgpu::GpuRequestDeviceErrorKind::OperationError,
+        message,
+    }
+}
+
+fn check_maximum(
+    name: &str,
+    value: u64,
+    adapter: u32,
+) -> Result<(), webgpu::GpuRequestDeviceError> {
+    if value > adapter as u64 {
+        return Err(operation_error(format!(
+            "Limit {name} of {value} is higher than the adapter's {adapter}"
+        )));
+    }
+    Ok(())
+}
+
+// Values lower than the default still get the default.
+fn maximum<T>(
+    name: &str,
+    value: u64,
+    limit: &mut T,
+    adapter: T,
+) -> Result<(), webgpu::GpuRequestDeviceError>
+where
+    T: Copy + Into<u64> + TryFrom<u64>,
+{
+    let adapter = adapter.into();
+    if value > adapter {
+        return Err(operation_error(format!(
+            "Limit {name} of {value} is higher than the adapter's {adapter}"
+        )));
+    }
+    if value > (*limit).into() {
+        // Can't fail, since the adapter's limit is a `T`.
+        if let Ok(value) = T::try_from(value) {
+            *limit = value;
+        }
+    }
+    Ok(())
+}
+
+// Values higher than the default still get the default.
+fn alignment(
+    name: &str,
+    value: u64,
+    limit: &mut u32,
+    adapter: u32,
+) -> Result<(), webgpu::GpuRequestDeviceError> {
+    if !value.is_power_of_two() {
+        return Err(operation_error(format!(
+            "Limit {name} of {value} is not a power of 2"
+        )));
+    }
+    if value < adapter as u64 {
+        return Err(operation_error(format!(
+            "Limit {name} of {value} is lower than the adapter's {adapter}"
+        )));
+    }
+    if value < *limit as u64 {
+        *limit = value as u32;
+    }
+    Ok(())
+}
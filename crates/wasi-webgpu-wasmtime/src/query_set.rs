@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+/// Keeps a query set's wgpu-core id valid.
+///
+/// wgpu-core only looks up the query sets of a pass once it ends, so passes hold a clone until then.
+/// The id is released once the guest destroyed or dropped the query set, and the last pass using it is gone.
+#[derive(Clone)]
+pub(crate) struct QuerySetHandle(Arc<QuerySetId>);
+
+struct QuerySetId {
+    instance: Arc<wgpu_core::global::Global>,
+    id: wgpu_core::id::QuerySetId,
+}
+
+impl QuerySetHandle {
+    pub(crate) fn new(
+        instance: Arc<wgpu_core::global::Global>,
+        id: wgpu_core::id::QuerySetId,
+    ) -> Self {
+        Self(Arc::new(QuerySetId { instance, id }))
+    }
+
+    pub(crate) fn id(&self) -> wgpu_core::id::QuerySetId {
+        self.0.id
+    }
+}
+
+impl Drop for QuerySetId {
+    fn drop(&mut self) {
+        self.instance.query_set_drop::<crate::Backend>(self.id);
+    }
+}
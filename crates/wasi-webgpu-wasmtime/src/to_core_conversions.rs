@@ -1,30 +1,152 @@
+use std::num::NonZeroU64;
+
 use wasmtime::component::ResourceTable;
 
+use crate::error::ValidationError;
+use crate::query_set::QuerySetHandle;
 use crate::wasi::webgpu::webgpu;
 
 pub trait ToCore<T> {
-    fn to_core(self, table: &ResourceTable) -> T;
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<T>;
 }
 
 impl<T> ToCore<T> for wasmtime::component::Resource<T>
 where
     T: Copy + 'static,
 {
-    fn to_core(self, table: &ResourceTable) -> T {
-        *table.get(&self).unwrap()
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<T> {
+        Ok(*table.get(&self)?)
+    }
+}
+
+impl ToCore<wgpu_core::id::TextureId> for wasmtime::component::Resource<crate::Texture> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::TextureId> {
+        Ok(table.get(&self)?.texture)
+    }
+}
+
+impl ToCore<wgpu_core::id::CommandEncoderId>
+    for wasmtime::component::Resource<crate::CommandEncoder>
+{
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::CommandEncoderId> {
+        Ok(table.get(&self)?.command_encoder)
+    }
+}
+
+impl ToCore<wgpu_core::id::QuerySetId> for wasmtime::component::Resource<crate::QuerySet> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::QuerySetId> {
+        // Callers report destroyed query sets as validation errors before getting here.
+        table
+            .get(&self)?
+            .query_set
+            .as_ref()
+            .map(QuerySetHandle::id)
+            .ok_or_else(|| wasmtime::Error::msg("Query set was destroyed"))
+    }
+}
+
+impl ToCore<wgpu_core::id::TextureViewId> for wasmtime::component::Resource<crate::TextureView> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::TextureViewId> {
+        Ok(table.get(&self)?.texture_view)
+    }
+}
+
+impl ToCore<wgpu_core::id::SamplerId> for wasmtime::component::Resource<crate::Sampler> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::SamplerId> {
+        Ok(table.get(&self)?.sampler)
+    }
+}
+
+impl ToCore<wgpu_core::id::ShaderModuleId> for wasmtime::component::Resource<crate::ShaderModule> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::ShaderModuleId> {
+        Ok(table.get(&self)?.shader_module)
+    }
+}
+
+impl ToCore<wgpu_core::id::BindGroupLayoutId>
+    for wasmtime::component::Resource<crate::BindGroupLayout>
+{
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::BindGroupLayoutId> {
+        Ok(table.get(&self)?.bind_group_layout)
+    }
+}
+
+impl ToCore<wgpu_core::id::BindGroupId> for wasmtime::component::Resource<crate::BindGroup> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::BindGroupId> {
+        Ok(table.get(&self)?.bind_group)
+    }
+}
+
+impl ToCore<wgpu_core::id::PipelineLayoutId>
+    for wasmtime::component::Resource<crate::PipelineLayout>
+{
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::PipelineLayoutId> {
+        Ok(table.get(&self)?.pipeline_layout)
+    }
+}
+
+impl ToCore<wgpu_core::id::RenderPipelineId>
+    for wasmtime::component::Resource<crate::RenderPipeline>
+{
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::RenderPipelineId> {
+        Ok(table.get(&self)?.render_pipeline)
+    }
+}
+
+impl ToCore<wgpu_core::id::RenderBundleId> for wasmtime::component::Resource<crate::RenderBundle> {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_core::id::RenderBundleId> {
+        Ok(table.get(&self)?.render_bundle)
     }
 }
 
 impl ToCore<wgpu_types::Extent3d> for webgpu::GpuExtent3D {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::Extent3d {
-        match self {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::Extent3d> {
+        // source: https://www.w3.org/TR/webgpu/#gpuextent3d-shape
+        Ok(match self {
             webgpu::GpuExtent3D::GpuExtent3DDict(extent_dict) => wgpu_types::Extent3d {
                 width: extent_dict.width,
-                height: extent_dict.height.unwrap(),
-                depth_or_array_layers: extent_dict.depth_or_array_layers.unwrap(),
+                height: extent_dict.height.unwrap_or(1),
+                depth_or_array_layers: extent_dict.depth_or_array_layers.unwrap_or(1),
             },
-            webgpu::GpuExtent3D::ListGpuIntegerCoordinate(_coordinates) => todo!(),
+            webgpu::GpuExtent3D::ListGpuIntegerCoordinate(coordinates) => match coordinates[..] {
+                [width] => wgpu_types::Extent3d {
+                    width,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                [width, height] => wgpu_types::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                [width, height, depth_or_array_layers] => wgpu_types::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers,
+                },
+                // wgpu-core rejects empty textures, `check_extent` reports these for everything else.
+                _ => wgpu_types::Extent3d {
+                    width: 0,
+                    height: 0,
+                    depth_or_array_layers: 0,
+                },
+            },
+        })
+    }
+}
+
+/// Extents given as a list need 1 to 3 coordinates.
+pub(crate) fn check_extent(extent: &webgpu::GpuExtent3D) -> Result<(), ValidationError> {
+    match extent {
+        webgpu::GpuExtent3D::ListGpuIntegerCoordinate(coordinates)
+            if !(1..=3).contains(&coordinates.len()) =>
+        {
+            Err(ValidationError(format!(
+                "Extent has {} coordinates, but needs 1 to 3",
+                coordinates.len()
+            )))
         }
+        _ => Ok(()),
     }
 }
 
@@ -34,49 +156,115 @@ impl ToCore<wgpu_core::binding_model::BindGroupDescriptor<'static>>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_core::binding_model::BindGroupDescriptor<'static> {
-        wgpu_core::binding_model::BindGroupDescriptor {
+    ) -> wasmtime::Result<wgpu_core::binding_model::BindGroupDescriptor<'static>> {
+        Ok(wgpu_core::binding_model::BindGroupDescriptor {
             label: self.label.map(|l| l.into()),
-            layout: self.layout.to_core(table),
-            entries: self.entries.into_iter().map(|e| e.to_core(table)).collect(),
-        }
+            layout: self.layout.to_core(table)?,
+            entries: self
+                .entries
+                .into_iter()
+                .filter_map(|e| e.to_core(table).transpose())
+                .collect::<wasmtime::Result<_>>()?,
+        })
     }
 }
 
-impl<'a> ToCore<wgpu_core::binding_model::BindGroupEntry<'a>> for webgpu::GpuBindGroupEntry {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::binding_model::BindGroupEntry<'a> {
-        wgpu_core::binding_model::BindGroupEntry {
+// Entries wgpu-core can't bind are left out, `check_bind_group_entries` reports them.
+impl<'a> ToCore<Option<wgpu_core::binding_model::BindGroupEntry<'a>>>
+    for webgpu::GpuBindGroupEntry
+{
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<Option<wgpu_core::binding_model::BindGroupEntry<'a>>> {
+        let Some(resource) = self.resource.to_core(table)? else {
+            return Ok(None);
+        };
+        Ok(Some(wgpu_core::binding_model::BindGroupEntry {
             binding: self.binding,
-            resource: self.resource.to_core(table),
-        }
+            resource,
+        }))
     }
 }
 
-impl<'a> ToCore<wgpu_core::binding_model::BindingResource<'a>> for webgpu::GpuBindingResource {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::binding_model::BindingResource<'a> {
-        match self {
+impl<'a> ToCore<Option<wgpu_core::binding_model::BindingResource<'a>>>
+    for webgpu::GpuBindingResource
+{
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<Option<wgpu_core::binding_model::BindingResource<'a>>> {
+        Ok(Some(match self {
+            webgpu::GpuBindingResource::GpuBufferBinding(buffer) if buffer.size == Some(0) => {
+                return Ok(None)
+            }
             webgpu::GpuBindingResource::GpuBufferBinding(buffer) => {
-                wgpu_core::binding_model::BindingResource::Buffer(buffer.to_core(table))
+                wgpu_core::binding_model::BindingResource::Buffer(buffer.to_core(table)?)
             }
-            webgpu::GpuBindingResource::GpuExternalTexture(_external_texture) => todo!(),
+            webgpu::GpuBindingResource::GpuExternalTexture(_external_texture) => return Ok(None),
             webgpu::GpuBindingResource::GpuSampler(sampler) => {
-                wgpu_core::binding_model::BindingResource::Sampler(sampler.to_core(table))
+                wgpu_core::binding_model::BindingResource::Sampler(sampler.to_core(table)?)
             }
             webgpu::GpuBindingResource::GpuTextureView(texture_view) => {
-                wgpu_core::binding_model::BindingResource::TextureView(texture_view.to_core(table))
+                wgpu_core::binding_model::BindingResource::TextureView(texture_view.to_core(table)?)
             }
+        }))
+    }
+}
+
+/// wgpu-core doesn't have external textures, so they can't be bound.
+/// Buffer bindings can't be empty, but wgpu-core only takes sizes that aren't 0.
+// source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createbindgroup
+pub(crate) fn check_bind_group_entries(
+    entries: &[webgpu::GpuBindGroupEntry],
+) -> Result<(), ValidationError> {
+    for entry in entries {
+        match &entry.resource {
+            webgpu::GpuBindingResource::GpuExternalTexture(_) => {
+                return Err(ValidationError(format!(
+                    "Bind group entry {} is an external texture, which isn't supported",
+                    entry.binding
+                )));
+            }
+            webgpu::GpuBindingResource::GpuBufferBinding(buffer) if buffer.size == Some(0) => {
+                return Err(ValidationError(format!(
+                    "Bind group entry {} binds 0 bytes of a buffer",
+                    entry.binding
+                )));
+            }
+            _ => {}
         }
     }
+    Ok(())
 }
 
-impl<'a> ToCore<wgpu_core::binding_model::BufferBinding> for webgpu::GpuBufferBinding {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::binding_model::BufferBinding {
-        let buffer = table.get(&self.buffer).unwrap();
-        wgpu_core::binding_model::BufferBinding {
+/// Leaving out the size binds the rest of the buffer.
+/// Binding 0 bytes is rejected, as wgpu-core only takes sizes that aren't 0.
+pub(crate) fn buffer_binding_size(
+    kind: &str,
+    size: Option<u64>,
+) -> Result<Option<NonZeroU64>, ValidationError> {
+    match size {
+        None => Ok(None),
+        Some(size) => NonZeroU64::new(size)
+            .map(Some)
+            .ok_or_else(|| ValidationError(format!("Can't bind 0 bytes of a {kind} buffer"))),
+    }
+}
+
+impl ToCore<wgpu_core::binding_model::BufferBinding> for webgpu::GpuBufferBinding {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::binding_model::BufferBinding> {
+        let buffer = table.get(&self.buffer)?;
+        Ok(wgpu_core::binding_model::BufferBinding {
             buffer_id: buffer.buffer,
-            offset: self.offset.unwrap(),
-            size: self.size.map(|s| s.try_into().unwrap()),
-        }
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gpubufferbinding
+            offset: self.offset.unwrap_or(0),
+            // Binding 0 bytes was already reported by `check_bind_group_entries`.
+            size: self.size.and_then(NonZeroU64::new),
+        })
     }
 }
 
@@ -86,29 +274,78 @@ impl<'a> ToCore<wgpu_types::CommandEncoderDescriptor<wgpu_core::Label<'a>>>
     fn to_core(
         self,
         _table: &ResourceTable,
-    ) -> wgpu_types::CommandEncoderDescriptor<wgpu_core::Label<'a>> {
-        wgpu_types::CommandEncoderDescriptor {
+    ) -> wasmtime::Result<wgpu_types::CommandEncoderDescriptor<wgpu_core::Label<'a>>> {
+        Ok(wgpu_types::CommandEncoderDescriptor {
             label: self.label.map(|l| l.into()),
-        }
+        })
+    }
+}
+
+impl<'a> ToCore<wgpu_core::command::RenderBundleEncoderDescriptor<'a>>
+    for webgpu::GpuRenderBundleEncoderDescriptor
+{
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::RenderBundleEncoderDescriptor<'a>> {
+        Ok(wgpu_core::command::RenderBundleEncoderDescriptor {
+            label: self.label.map(|l| l.into()),
+            color_formats: self
+                .color_formats
+                .into_iter()
+                .map(|format| format.map(|format| format.into()))
+                .collect::<Vec<_>>()
+                .into(),
+            depth_stencil: self.depth_stencil_format.map(|format| {
+                // source: https://www.w3.org/TR/webgpu/#dictdef-gpurenderbundleencoderdescriptor
+                wgpu_types::RenderBundleDepthStencil {
+                    format: format.into(),
+                    depth_read_only: self.depth_read_only.unwrap_or(false),
+                    stencil_read_only: self.stencil_read_only.unwrap_or(false),
+                }
+            }),
+            // source: https://www.w3.org/TR/webgpu/#dom-gpurenderpasslayout-samplecount
+            sample_count: self.sample_count.unwrap_or(1),
+            multiview: None,
+        })
+    }
+}
+
+impl<'a> ToCore<wgpu_core::command::RenderBundleDescriptor<'a>>
+    for webgpu::GpuRenderBundleDescriptor
+{
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::RenderBundleDescriptor<'a>> {
+        Ok(wgpu_core::command::RenderBundleDescriptor {
+            label: self.label.map(|l| l.into()),
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::ShaderModuleDescriptor<'a>>
     for webgpu::GpuShaderModuleDescriptor
 {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_core::pipeline::ShaderModuleDescriptor<'a> {
-        wgpu_core::pipeline::ShaderModuleDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::ShaderModuleDescriptor<'a>> {
+        Ok(wgpu_core::pipeline::ShaderModuleDescriptor {
             label: self.label.map(|label| label.into()),
             shader_bound_checks: wgpu_types::ShaderBoundChecks::new(),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::resource::TextureViewDescriptor<'a>>
     for webgpu::GpuTextureViewDescriptor
 {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_core::resource::TextureViewDescriptor<'a> {
-        wgpu_core::resource::TextureViewDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::resource::TextureViewDescriptor<'a>> {
+        Ok(wgpu_core::resource::TextureViewDescriptor {
             label: self.label.map(|l| l.into()),
             format: self.format.map(|f| f.into()),
             dimension: self.dimension.map(|d| d.into()),
@@ -121,7 +358,7 @@ impl<'a> ToCore<wgpu_core::resource::TextureViewDescriptor<'a>>
             //     base_array_layer: self.base_array_layer,
             //     array_layer_count: self.array_layer_count,
             // }
-        }
+        })
     }
 }
 
@@ -131,65 +368,80 @@ impl<'a> ToCore<wgpu_core::binding_model::PipelineLayoutDescriptor<'a>>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_core::binding_model::PipelineLayoutDescriptor<'a> {
-        wgpu_core::binding_model::PipelineLayoutDescriptor {
+    ) -> wasmtime::Result<wgpu_core::binding_model::PipelineLayoutDescriptor<'a>> {
+        Ok(wgpu_core::binding_model::PipelineLayoutDescriptor {
             label: self.label.map(|l| l.into()),
             bind_group_layouts: self
                 .bind_group_layouts
                 .into_iter()
-                .map(|bind_group_layout| *table.get(&bind_group_layout).unwrap())
-                .collect::<Vec<_>>()
+                .map(|bind_group_layout| bind_group_layout.to_core(table))
+                .collect::<wasmtime::Result<Vec<_>>>()?
                 .into(),
             push_constant_ranges: vec![].into(),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::RenderPipelineDescriptor<'a>>
     for webgpu::GpuRenderPipelineDescriptor
 {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::pipeline::RenderPipelineDescriptor<'a> {
-        wgpu_core::pipeline::RenderPipelineDescriptor {
-            // TODO: remove defaults
-            label: Default::default(),
-            layout: self.layout.map(|l| l.to_core(table)),
-            vertex: self.vertex.to_core(table),
-            primitive: self.primitive.map(|p| p.to_core(table)).unwrap(),
-            depth_stencil: self.depth_stencil.map(|ds| ds.to_core(table)),
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::RenderPipelineDescriptor<'a>> {
+        Ok(wgpu_core::pipeline::RenderPipelineDescriptor {
+            label: self.label.map(|l| l.into()),
+            layout: self.layout.map(|l| l.to_core(table)).transpose()?,
+            vertex: self.vertex.to_core(table)?,
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gpurenderpipelinedescriptor
+            primitive: self
+                .primitive
+                .map(|p| p.to_core(table))
+                .transpose()?
+                .unwrap_or_default(),
+            depth_stencil: self.depth_stencil.map(|ds| ds.to_core(table)).transpose()?,
             multisample: self
                 .multisample
                 .map(|ms| ms.to_core(table))
+                .transpose()?
                 .unwrap_or_default(),
-            fragment: self.fragment.map(|f| f.to_core(table)),
+            fragment: self.fragment.map(|f| f.to_core(table)).transpose()?,
             multiview: Default::default(),
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_types::MultisampleState> for webgpu::GpuMultisampleState {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::MultisampleState {
-        wgpu_types::MultisampleState {
-            count: self.count.unwrap(),
-            mask: self.mask.unwrap().into(),
-            alpha_to_coverage_enabled: self.alpha_to_coverage_enabled.unwrap(),
-        }
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::MultisampleState> {
+        // source: https://www.w3.org/TR/webgpu/#dictdef-gpumultisamplestate
+        Ok(wgpu_types::MultisampleState {
+            count: self.count.unwrap_or(1),
+            mask: self.mask.unwrap_or(0xFFFFFFFF).into(),
+            alpha_to_coverage_enabled: self.alpha_to_coverage_enabled.unwrap_or(false),
+        })
     }
 }
 
 impl ToCore<wgpu_types::DepthStencilState> for webgpu::GpuDepthStencilState {
-    fn to_core(self, table: &ResourceTable) -> wgpu_types::DepthStencilState {
-        wgpu_types::DepthStencilState {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_types::DepthStencilState> {
+        Ok(wgpu_types::DepthStencilState {
             format: self.format.into(),
-            depth_write_enabled: self.depth_write_enabled.unwrap().into(),
-            depth_compare: self.depth_compare.unwrap().into(),
+            // Only formats without depth can leave these out, `check_depth_stencil_state` reports the others.
+            depth_write_enabled: self.depth_write_enabled.unwrap_or(false),
+            depth_compare: self
+                .depth_compare
+                .map(|c| c.into())
+                .unwrap_or(wgpu_types::CompareFunction::Always),
             stencil: wgpu_types::StencilState {
                 front: self
                     .stencil_front
                     .map(|f| f.to_core(table))
+                    .transpose()?
                     .unwrap_or_default(),
                 back: self
                     .stencil_back
                     .map(|b| b.to_core(table))
+                    .transpose()?
                     .unwrap_or_default(),
                 read_mask: self.stencil_read_mask.unwrap_or_default(),
                 write_mask: self.stencil_write_mask.unwrap_or_default(),
@@ -199,13 +451,43 @@ impl ToCore<wgpu_types::DepthStencilState> for webgpu::GpuDepthStencilState {
                 slope_scale: self.depth_bias_slope_scale.unwrap_or_default(),
                 clamp: self.depth_bias_clamp.unwrap_or_default(),
             },
-        }
+        })
     }
 }
 
+/// Formats with depth need `depth-write-enabled`, and `depth-compare` too when it's used.
+// source: https://www.w3.org/TR/webgpu/#abstract-opdef-validating-gpudepthstencilstate
+pub(crate) fn check_depth_stencil_state(
+    state: &webgpu::GpuDepthStencilState,
+) -> Result<(), ValidationError> {
+    if !wgpu_types::TextureFormat::from(state.format).has_depth_aspect() {
+        return Ok(());
+    }
+    let Some(depth_write_enabled) = state.depth_write_enabled else {
+        return Err(ValidationError(
+            "Formats with depth need depth-write-enabled".to_string(),
+        ));
+    };
+    let uses_depth_fail_op = |face: &Option<webgpu::GpuStencilFaceState>| {
+        face.as_ref()
+            .and_then(|face| face.depth_fail_op)
+            .is_some_and(|op| op != webgpu::GpuStencilOperation::Keep)
+    };
+    if state.depth_compare.is_none()
+        && (depth_write_enabled
+            || uses_depth_fail_op(&state.stencil_front)
+            || uses_depth_fail_op(&state.stencil_back))
+    {
+        return Err(ValidationError(
+            "Formats with depth need depth-compare when depth is written".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl ToCore<wgpu_types::StencilFaceState> for webgpu::GpuStencilFaceState {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::StencilFaceState {
-        wgpu_types::StencilFaceState {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::StencilFaceState> {
+        Ok(wgpu_types::StencilFaceState {
             compare: self
                 .compare
                 .map(|c| c.into())
@@ -222,30 +504,33 @@ impl ToCore<wgpu_types::StencilFaceState> for webgpu::GpuStencilFaceState {
                 .pass_op
                 .map(|po| po.into())
                 .unwrap_or(wgpu_types::StencilOperation::Keep),
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_types::PrimitiveState> for webgpu::GpuPrimitiveState {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::PrimitiveState {
-        wgpu_types::PrimitiveState {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::PrimitiveState> {
+        Ok(wgpu_types::PrimitiveState {
             topology: self.topology.map(|t| t.into()).unwrap_or_default(),
             strip_index_format: self.strip_index_format.map(|f| f.into()),
             front_face: self.front_face.map(|x| x.into()).unwrap_or_default(),
-            cull_mode: self.cull_mode.map(|cm| cm.into()),
+            cull_mode: self.cull_mode.and_then(|cm| cm.into()),
             unclipped_depth: self.unclipped_depth.unwrap_or_default(),
             // TODO: remove defaults
             polygon_mode: Default::default(),
             conservative: Default::default(),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::FragmentState<'a>> for webgpu::GpuFragmentState {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::pipeline::FragmentState<'a> {
-        wgpu_core::pipeline::FragmentState {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::FragmentState<'a>> {
+        Ok(wgpu_core::pipeline::FragmentState {
             stage: wgpu_core::pipeline::ProgrammableStageDescriptor {
-                module: self.module.to_core(table),
+                module: self.module.to_core(table)?,
                 entry_point: Some(self.entry_point.into()),
                 constants: Default::default(),
                 zero_initialize_workgroup_memory: true,
@@ -253,38 +538,39 @@ impl<'a> ToCore<wgpu_core::pipeline::FragmentState<'a>> for webgpu::GpuFragmentS
             targets: self
                 .targets
                 .into_iter()
-                .map(|t| t.map(|t| t.to_core(table)))
-                .collect::<Vec<_>>()
+                .map(|t| t.map(|t| t.to_core(table)).transpose())
+                .collect::<wasmtime::Result<Vec<_>>>()?
                 .into(),
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_types::ColorTargetState> for webgpu::GpuColorTargetState {
-    fn to_core(self, table: &ResourceTable) -> wgpu_types::ColorTargetState {
-        wgpu_types::ColorTargetState {
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_types::ColorTargetState> {
+        Ok(wgpu_types::ColorTargetState {
             format: self.format.into(),
-            blend: self.blend.map(|b| b.to_core(table)),
+            blend: self.blend.map(|b| b.to_core(table)).transpose()?,
             write_mask: self
                 .write_mask
-                .map(|wm| wgpu_types::ColorWrites::from_bits(wm).unwrap())
+                // wgpu-core reports unknown bits.
+                .map(wgpu_types::ColorWrites::from_bits_retain)
                 .unwrap_or_default(),
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_types::BlendState> for webgpu::GpuBlendState {
-    fn to_core(self, table: &ResourceTable) -> wgpu_types::BlendState {
-        wgpu_types::BlendState {
-            color: self.color.to_core(table),
-            alpha: self.alpha.to_core(table),
-        }
+    fn to_core(self, table: &ResourceTable) -> wasmtime::Result<wgpu_types::BlendState> {
+        Ok(wgpu_types::BlendState {
+            color: self.color.to_core(table)?,
+            alpha: self.alpha.to_core(table)?,
+        })
     }
 }
 
 impl ToCore<wgpu_types::BlendComponent> for webgpu::GpuBlendComponent {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BlendComponent {
-        wgpu_types::BlendComponent {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::BlendComponent> {
+        Ok(wgpu_types::BlendComponent {
             // TODO: link to spec for defaults.
             src_factor: self
                 .src_factor
@@ -298,48 +584,64 @@ impl ToCore<wgpu_types::BlendComponent> for webgpu::GpuBlendComponent {
                 .operation
                 .map(|x| x.into())
                 .unwrap_or(wgpu_types::BlendOperation::Add),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::VertexState<'a>> for webgpu::GpuVertexState {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::pipeline::VertexState<'a> {
-        wgpu_core::pipeline::VertexState {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::VertexState<'a>> {
+        Ok(wgpu_core::pipeline::VertexState {
             stage: wgpu_core::pipeline::ProgrammableStageDescriptor {
-                module: self.module.to_core(table),
+                module: self.module.to_core(table)?,
                 entry_point: Some(self.entry_point.into()),
                 constants: Default::default(),
                 zero_initialize_workgroup_memory: true,
             },
             buffers: self
                 .buffers
-                .map(|buffer| buffer.into_iter().map(|b| b.to_core(table)).collect())
+                .map(|buffer| {
+                    buffer
+                        .into_iter()
+                        .map(|b| b.to_core(table))
+                        .collect::<wasmtime::Result<_>>()
+                })
+                .transpose()?
                 .unwrap_or_default(),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::VertexBufferLayout<'a>> for webgpu::GpuVertexBufferLayout {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::pipeline::VertexBufferLayout<'a> {
-        wgpu_core::pipeline::VertexBufferLayout {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::VertexBufferLayout<'a>> {
+        Ok(wgpu_core::pipeline::VertexBufferLayout {
             array_stride: self.array_stride,
-            step_mode: self.step_mode.unwrap().into(),
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gpuvertexbufferlayout
+            step_mode: self
+                .step_mode
+                .map(|s| s.into())
+                .unwrap_or(wgpu_types::VertexStepMode::Vertex),
             attributes: self
                 .attributes
                 .into_iter()
                 .map(|a| a.to_core(table))
-                .collect(),
-        }
+                .collect::<wasmtime::Result<_>>()?,
+        })
     }
 }
 
 impl ToCore<wgpu_types::VertexAttribute> for webgpu::GpuVertexAttribute {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::VertexAttribute {
-        wgpu_types::VertexAttribute {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::VertexAttribute> {
+        Ok(wgpu_types::VertexAttribute {
             format: self.format.into(),
             offset: self.offset,
             shader_location: self.shader_location,
-        }
+        })
     }
 }
 
@@ -349,15 +651,19 @@ impl<'a> ToCore<wgpu_types::TextureDescriptor<wgpu_core::Label<'a>, Vec<wgpu_typ
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_types::TextureDescriptor<wgpu_core::Label<'a>, Vec<wgpu_types::TextureFormat>> {
-        wgpu_types::TextureDescriptor {
+    ) -> wasmtime::Result<
+        wgpu_types::TextureDescriptor<wgpu_core::Label<'a>, Vec<wgpu_types::TextureFormat>>,
+    > {
+        Ok(wgpu_types::TextureDescriptor {
             label: self.label.map(|l| l.into()),
-            size: self.size.to_core(table),
-            mip_level_count: self.mip_level_count.unwrap(),
-            sample_count: self.sample_count.unwrap(),
+            size: self.size.to_core(table)?,
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gputexturedescriptor
+            mip_level_count: self.mip_level_count.unwrap_or(1),
+            sample_count: self.sample_count.unwrap_or(1),
             dimension: self.dimension.into(),
             format: self.format.into(),
-            usage: wgpu_types::TextureUsages::from_bits(self.usage).unwrap(),
+            // wgpu-core reports unknown bits.
+            usage: wgpu_types::TextureUsages::from_bits_retain(self.usage),
             view_formats: self
                 .view_formats
                 .map(|view_formats| {
@@ -367,29 +673,46 @@ impl<'a> ToCore<wgpu_types::TextureDescriptor<wgpu_core::Label<'a>, Vec<wgpu_typ
                         .collect()
                 })
                 .unwrap_or_default(),
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::resource::SamplerDescriptor<'a>> for webgpu::GpuSamplerDescriptor {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_core::resource::SamplerDescriptor<'a> {
-        wgpu_core::resource::SamplerDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::resource::SamplerDescriptor<'a>> {
+        Ok(wgpu_core::resource::SamplerDescriptor {
             label: self.label.map(|l| l.into()),
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gpusamplerdescriptor
             address_modes: [
-                self.address_mode_u.unwrap().into(),
-                self.address_mode_v.unwrap().into(),
-                self.address_mode_w.unwrap().into(),
-            ],
-            mag_filter: self.mag_filter.unwrap().into(),
-            min_filter: self.min_filter.unwrap().into(),
-            mipmap_filter: self.mipmap_filter.unwrap().into(),
-            lod_min_clamp: self.lod_min_clamp.unwrap(),
-            lod_max_clamp: self.lod_max_clamp.unwrap(),
+                self.address_mode_u,
+                self.address_mode_v,
+                self.address_mode_w,
+            ]
+            .map(|address_mode| {
+                address_mode
+                    .map(|a| a.into())
+                    .unwrap_or(wgpu_types::AddressMode::ClampToEdge)
+            }),
+            mag_filter: self
+                .mag_filter
+                .map(|f| f.into())
+                .unwrap_or(wgpu_types::FilterMode::Nearest),
+            min_filter: self
+                .min_filter
+                .map(|f| f.into())
+                .unwrap_or(wgpu_types::FilterMode::Nearest),
+            mipmap_filter: self
+                .mipmap_filter
+                .map(|f| f.into())
+                .unwrap_or(wgpu_types::FilterMode::Nearest),
+            lod_min_clamp: self.lod_min_clamp.unwrap_or(0.0),
+            lod_max_clamp: self.lod_max_clamp.unwrap_or(32.0),
             compare: self.compare.map(|compare| compare.into()),
-            // TODO: should this be coming from self.anisotropy_clamp?
-            anisotropy_clamp: 1,
+            anisotropy_clamp: self.max_anisotropy.unwrap_or(1),
             border_color: Default::default(),
-        }
+        })
     }
 }
 
@@ -399,9 +722,9 @@ impl<'a> ToCore<wgpu_types::CommandBufferDescriptor<wgpu_core::Label<'a>>>
     fn to_core(
         self,
         _table: &ResourceTable,
-    ) -> wgpu_types::CommandBufferDescriptor<wgpu_core::Label<'a>> {
+    ) -> wasmtime::Result<wgpu_types::CommandBufferDescriptor<wgpu_core::Label<'a>>> {
         // TODO:
-        Default::default()
+        Ok(Default::default())
     }
 }
 
@@ -411,80 +734,127 @@ impl<'a> ToCore<wgpu_core::binding_model::BindGroupLayoutDescriptor<'a>>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_core::binding_model::BindGroupLayoutDescriptor<'a> {
-        wgpu_core::binding_model::BindGroupLayoutDescriptor {
+    ) -> wasmtime::Result<wgpu_core::binding_model::BindGroupLayoutDescriptor<'a>> {
+        Ok(wgpu_core::binding_model::BindGroupLayoutDescriptor {
             label: self.label.map(|l| l.into()),
             entries: self
                 .entries
                 .into_iter()
-                .map(|entry| entry.to_core(table))
-                .collect(),
-        }
+                .filter_map(|entry| entry.to_core(table).transpose())
+                .collect::<wasmtime::Result<_>>()?,
+        })
     }
 }
 
-impl ToCore<wgpu_types::BindGroupLayoutEntry> for webgpu::GpuBindGroupLayoutEntry {
-    fn to_core(self, table: &ResourceTable) -> wgpu_types::BindGroupLayoutEntry {
-        wgpu_types::BindGroupLayoutEntry {
-            binding: self.binding.into(),
-            // TODO:
-            visibility: wgpu_types::ShaderStages::from_bits(self.visibility).unwrap(),
-            ty: match (
-                self.buffer,
-                self.sampler,
-                self.texture,
-                self.storage_texture,
-                self.external_texture,
-            ) {
-                (Some(buffer), None, None, None, None) => buffer.to_core(table),
-                (None, Some(sampler), None, None, None) => sampler.to_core(table),
-                (None, None, Some(texture), None, None) => texture.to_core(table),
-                (None, None, None, Some(storage_texture), None) => storage_texture.to_core(table),
-                (None, None, None, None, Some(external_texture)) => external_texture.to_core(table),
-                (None, None, None, None, None) => todo!(),
-                _ => panic!("Can't have multiple ..."),
-            },
+// Entries wgpu-core can't represent are left out, `check_bind_group_layout_entries` reports them.
+impl ToCore<Option<wgpu_types::BindGroupLayoutEntry>> for webgpu::GpuBindGroupLayoutEntry {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<Option<wgpu_types::BindGroupLayoutEntry>> {
+        let ty = match (
+            self.buffer,
+            self.sampler,
+            self.texture,
+            self.storage_texture,
+            self.external_texture,
+        ) {
+            (Some(buffer), None, None, None, None) => buffer.to_core(table)?,
+            (None, Some(sampler), None, None, None) => sampler.to_core(table)?,
+            (None, None, Some(texture), None, None) => texture.to_core(table)?,
+            (None, None, None, Some(storage_texture), None) => storage_texture.to_core(table)?,
+            _ => return Ok(None),
+        };
+        Ok(Some(wgpu_types::BindGroupLayoutEntry {
+            binding: self.binding,
+            // wgpu-core reports unknown bits.
+            visibility: wgpu_types::ShaderStages::from_bits_retain(self.visibility),
+            ty,
             // TODO:
             count: Default::default(),
+        }))
+    }
+}
+
+/// Every entry needs exactly one binding type, and wgpu-core doesn't have external textures.
+// source: https://www.w3.org/TR/webgpu/#dom-gpudevice-createbindgrouplayout
+pub(crate) fn check_bind_group_layout_entries(
+    entries: &[webgpu::GpuBindGroupLayoutEntry],
+) -> Result<(), ValidationError> {
+    for entry in entries {
+        let binding_types = [
+            entry.buffer.is_some(),
+            entry.sampler.is_some(),
+            entry.texture.is_some(),
+            entry.storage_texture.is_some(),
+            entry.external_texture.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_some| *is_some)
+        .count();
+        if binding_types != 1 {
+            return Err(ValidationError(format!(
+                "Bind group layout entry {} has {binding_types} binding types, but needs exactly 1",
+                entry.binding
+            )));
+        }
+        if entry.external_texture.is_some() {
+            return Err(ValidationError(format!(
+                "Bind group layout entry {} is an external texture, which isn't supported",
+                entry.binding
+            )));
         }
     }
+    Ok(())
 }
 
 impl ToCore<wgpu_types::BindingType> for webgpu::GpuBufferBindingLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BindingType {
-        wgpu_types::BindingType::Buffer {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::BindingType> {
+        Ok(wgpu_types::BindingType::Buffer {
             ty: self.type_.map(|t| t.into()).unwrap_or_default(),
             has_dynamic_offset: self.has_dynamic_offset.unwrap_or_default(),
-            min_binding_size: self.min_binding_size.map(|x| x.try_into().unwrap()),
-        }
+            // A minimum of 0 means there is none.
+            min_binding_size: self.min_binding_size.and_then(NonZeroU64::new),
+        })
     }
 }
 
 impl ToCore<wgpu_types::BindingType> for webgpu::GpuSamplerBindingLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BindingType {
-        wgpu_types::BindingType::Sampler(self.type_.map(|t| t.into()).unwrap())
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::BindingType> {
+        // source: https://www.w3.org/TR/webgpu/#dictdef-gpusamplerbindinglayout
+        Ok(wgpu_types::BindingType::Sampler(
+            self.type_
+                .map(|t| t.into())
+                .unwrap_or(wgpu_types::SamplerBindingType::Filtering),
+        ))
     }
 }
 
 impl ToCore<wgpu_types::BindingType> for webgpu::GpuTextureBindingLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BindingType {
-        wgpu_types::BindingType::Texture {
-            sample_type: self.sample_type.unwrap().into(),
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::BindingType> {
+        // source: https://www.w3.org/TR/webgpu/#dictdef-gputexturebindinglayout
+        Ok(wgpu_types::BindingType::Texture {
+            sample_type: self
+                .sample_type
+                .map(|t| t.into())
+                .unwrap_or(wgpu_types::TextureSampleType::Float { filterable: true }),
             view_dimension: self.view_dimension.into(),
             multisampled: self.multisampled.unwrap_or_default(),
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_types::BindingType> for webgpu::GpuStorageTextureBindingLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BindingType {
-        todo!()
-    }
-}
-
-impl ToCore<wgpu_types::BindingType> for webgpu::GpuExternalTextureBindingLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BindingType {
-        todo!()
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::BindingType> {
+        // source: https://www.w3.org/TR/webgpu/#dictdef-gpustoragetexturebindinglayout
+        Ok(wgpu_types::BindingType::StorageTexture {
+            access: self
+                .access
+                .map(|a| a.into())
+                .unwrap_or(wgpu_types::StorageTextureAccess::WriteOnly),
+            format: self.format.into(),
+            view_dimension: self.view_dimension.into(),
+        })
     }
 }
 
@@ -514,22 +884,22 @@ impl ToCore<wgpu_core::command::RenderPassDepthStencilAttachment>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_core::command::RenderPassDepthStencilAttachment {
-        wgpu_core::command::RenderPassDepthStencilAttachment {
-            view: self.view.to_core(table),
+    ) -> wasmtime::Result<wgpu_core::command::RenderPassDepthStencilAttachment> {
+        Ok(wgpu_core::command::RenderPassDepthStencilAttachment {
+            view: self.view.to_core(table)?,
             depth: pass_channel_from_options(
                 self.depth_load_op.map(|x| x.into()),
                 self.depth_store_op.map(|x| x.into()),
-                self.depth_clear_value.map(|x| x.into()),
-                self.depth_read_only.map(|x| x.into()),
+                self.depth_clear_value,
+                self.depth_read_only,
             ),
             stencil: pass_channel_from_options(
                 self.stencil_load_op.map(|x| x.into()),
                 self.stencil_store_op.map(|x| x.into()),
-                self.stencil_clear_value.map(|x| x.into()),
-                self.stencil_read_only.map(|x| x.into()),
+                self.stencil_clear_value,
+                self.stencil_read_only,
             ),
-        }
+        })
     }
 }
 
@@ -539,68 +909,168 @@ fn pass_channel_from_options<V: Default + Copy>(
     clear_value: Option<V>,
     read_only: Option<bool>,
 ) -> wgpu_core::command::PassChannel<V> {
-    match (load_op, store_op, clear_value) {
-        (Some(load_op), Some(store_op), Some(clear_value)) => wgpu_core::command::PassChannel {
-            load_op,
-            store_op,
-            clear_value,
-            // TODO: why default to false?
-            read_only: read_only.unwrap_or(false),
-        },
-        (None, None, None) => wgpu_core::command::PassChannel {
-            load_op: wgpu_core::command::LoadOp::Load,
-            store_op: wgpu_core::command::StoreOp::Store,
-            clear_value: V::default(),
-            // TODO: why default to false?
-            read_only: read_only.unwrap_or(false),
+    // Missing ops become load and store, which is what wgpu-core wants for read only channels.
+    // `check_depth_stencil_attachment` reports the combinations that aren't valid.
+    wgpu_core::command::PassChannel {
+        load_op: load_op.unwrap_or(wgpu_core::command::LoadOp::Load),
+        store_op: store_op.unwrap_or(wgpu_core::command::StoreOp::Store),
+        // The clear value is only used when clearing.
+        clear_value: clear_value.unwrap_or_default(),
+        // TODO: why default to false?
+        read_only: read_only.unwrap_or(false),
+    }
+}
+
+/// Lists need exactly one value per channel.
+// source: https://www.w3.org/TR/webgpu/#abstract-opdef-validate-gpucolor-shape
+pub(crate) fn color(color: webgpu::GpuColor) -> Result<wgpu_types::Color, ValidationError> {
+    match color {
+        webgpu::GpuColorDictOrListF64::GpuColorDict(webgpu::GpuColorDict { r, g, b, a }) => {
+            Ok(wgpu_types::Color { r, g, b, a })
+        }
+        webgpu::GpuColorDictOrListF64::ListF64(list) => match list[..] {
+            [r, g, b, a] => Ok(wgpu_types::Color { r, g, b, a }),
+            _ => Err(ValidationError(format!(
+                "Color has {} components instead of 4",
+                list.len()
+            ))),
         },
-        _ => todo!(),
     }
 }
 
+/// Read only channels can't have ops, the others need both or neither,
+/// and clearing depth needs a value to clear to.
+// source: https://www.w3.org/TR/webgpu/#abstract-opdef-gpurenderpassdepthstencilattachment-gpurenderpassdepthstencilattachment-valid-usage
+pub(crate) fn check_depth_stencil_attachment(
+    attachment: &webgpu::GpuRenderPassDepthStencilAttachment,
+) -> Result<(), ValidationError> {
+    check_pass_channel(
+        "depth",
+        attachment.depth_load_op.is_some(),
+        attachment.depth_store_op.is_some(),
+        attachment.depth_read_only.unwrap_or(false),
+    )?;
+    check_pass_channel(
+        "stencil",
+        attachment.stencil_load_op.is_some(),
+        attachment.stencil_store_op.is_some(),
+        attachment.stencil_read_only.unwrap_or(false),
+    )?;
+    if let Some(webgpu::GpuLoadOp::Clear) = attachment.depth_load_op {
+        match attachment.depth_clear_value {
+            None => {
+                return Err(ValidationError(
+                    "Clearing depth needs a depth clear value".to_string(),
+                ))
+            }
+            Some(value) if !(0.0..=1.0).contains(&value) => {
+                return Err(ValidationError(format!(
+                    "Depth clear value {value} isn't between 0 and 1"
+                )))
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn check_pass_channel(
+    aspect: &str,
+    load_op: bool,
+    store_op: bool,
+    read_only: bool,
+) -> Result<(), ValidationError> {
+    if read_only && (load_op || store_op) {
+        return Err(ValidationError(format!(
+            "Read only {aspect} can't have a load or store op"
+        )));
+    }
+    if load_op != store_op {
+        return Err(ValidationError(format!(
+            "The {aspect} load and store ops have to be given together"
+        )));
+    }
+    Ok(())
+}
+
 impl ToCore<wgpu_core::command::RenderPassColorAttachment>
     for webgpu::GpuRenderPassColorAttachment
 {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::command::RenderPassColorAttachment {
-        wgpu_core::command::RenderPassColorAttachment {
-            view: self.view.to_core(table),
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::RenderPassColorAttachment> {
+        Ok(wgpu_core::command::RenderPassColorAttachment {
+            view: self.view.to_core(table)?,
             resolve_target: self
                 .resolve_target
-                .map(|resolve_target| resolve_target.to_core(table)),
+                .map(|resolve_target| resolve_target.to_core(table))
+                .transpose()?,
             channel: pass_channel_from_options(
                 Some(self.load_op.into()),
                 Some(self.store_op.into()),
-                self.clear_value.map(|c| c.into()),
+                self.clear_value.map(color).transpose()?,
                 // TODO: why default to false?
                 Some(false),
             ),
             // TODO: didn't use self.depth_slice
-        }
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_types::BufferDescriptor<wgpu_core::Label<'a>>>
     for webgpu::GpuBufferDescriptor
 {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::BufferDescriptor<wgpu_core::Label<'a>> {
-        wgpu_types::BufferDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_types::BufferDescriptor<wgpu_core::Label<'a>>> {
+        Ok(wgpu_types::BufferDescriptor {
             label: self.label.map(|l| l.into()),
             size: self.size,
-            usage: wgpu_types::BufferUsages::from_bits(self.usage).unwrap(),
+            // wgpu-core reports unknown bits.
+            usage: wgpu_types::BufferUsages::from_bits_retain(self.usage),
             mapped_at_creation: self.mapped_at_creation.unwrap_or_default(),
-        }
+        })
+    }
+}
+
+impl ToCore<wgpu_core::instance::RequestAdapterOptions> for webgpu::GpuRequestAdapterOptions {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::instance::RequestAdapterOptions> {
+        Ok(wgpu_core::instance::RequestAdapterOptions {
+            power_preference: self.power_preference.map(|p| p.into()).unwrap_or_default(),
+            force_fallback_adapter: self.force_fallback_adapter.unwrap_or_default(),
+            compatible_surface: None,
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_types::DeviceDescriptor<wgpu_core::Label<'a>>>
     for webgpu::GpuDeviceDescriptor
 {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::DeviceDescriptor<wgpu_core::Label<'a>> {
-        wgpu_types::DeviceDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_types::DeviceDescriptor<wgpu_core::Label<'a>>> {
+        Ok(wgpu_types::DeviceDescriptor {
             label: self.label.map(|l| l.into()),
+            required_features: self
+                .required_features
+                .map(|features| {
+                    features
+                        .into_iter()
+                        .fold(wgpu_types::Features::empty(), |acc, feature| {
+                            acc | feature.into()
+                        })
+                })
+                .unwrap_or_default(),
+            // Needs the adapter's limits, so `request_device` fills these in.
             // TODO: Don't default
             ..Default::default()
-        }
+        })
     }
 }
 
@@ -610,60 +1080,112 @@ impl ToCore<wgpu_types::ImageCopyTexture<wgpu_core::id::TextureId>>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_types::ImageCopyTexture<wgpu_core::id::TextureId> {
-        wgpu_types::ImageCopyTexture {
-            texture: self.texture.to_core(table),
-            mip_level: self.mip_level.unwrap(),
-            origin: self.origin.unwrap().to_core(table),
-            aspect: self.aspect.unwrap().into(),
-        }
+    ) -> wasmtime::Result<wgpu_types::ImageCopyTexture<wgpu_core::id::TextureId>> {
+        Ok(wgpu_types::ImageCopyTexture {
+            texture: self.texture.to_core(table)?,
+            // source: https://www.w3.org/TR/webgpu/#dictdef-gpuimagecopytexture
+            mip_level: self.mip_level.unwrap_or(0),
+            origin: self
+                .origin
+                .map(|o| o.to_core(table))
+                .transpose()?
+                .unwrap_or_default(),
+            aspect: self
+                .aspect
+                .map(|a| a.into())
+                .unwrap_or(wgpu_types::TextureAspect::All),
+        })
     }
 }
 
 impl ToCore<wgpu_types::Origin3d> for webgpu::GpuOrigin3DDictOrListGpuIntegerCoordinate {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::Origin3d {
-        match self {
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::Origin3d> {
+        // source: https://www.w3.org/TR/webgpu/#gpuorigin3d-shape
+        Ok(match self {
             webgpu::GpuOrigin3DDictOrListGpuIntegerCoordinate::GpuOrigin3DDict(dict) => {
                 wgpu_types::Origin3d {
-                    x: dict.x.unwrap(),
-                    y: dict.y.unwrap(),
-                    z: dict.z.unwrap(),
+                    x: dict.x.unwrap_or(0),
+                    y: dict.y.unwrap_or(0),
+                    z: dict.z.unwrap_or(0),
                 }
             }
-            webgpu::GpuOrigin3DDictOrListGpuIntegerCoordinate::ListGpuIntegerCoordinate(_) => {
-                todo!()
-            }
-        }
+            webgpu::GpuOrigin3DDictOrListGpuIntegerCoordinate::ListGpuIntegerCoordinate(
+                coordinates,
+            ) => match coordinates[..] {
+                [x] => wgpu_types::Origin3d { x, y: 0, z: 0 },
+                [x, y] => wgpu_types::Origin3d { x, y, z: 0 },
+                [x, y, z] => wgpu_types::Origin3d { x, y, z },
+                // Including lists that are too long, which `check_image_copy_texture` reports.
+                _ => wgpu_types::Origin3d::ZERO,
+            },
+        })
+    }
+}
+
+/// Origins given as a list can't have more than 3 coordinates.
+pub(crate) fn check_image_copy_texture(
+    image_copy_texture: &webgpu::GpuImageCopyTexture,
+) -> Result<(), ValidationError> {
+    match &image_copy_texture.origin {
+        Some(webgpu::GpuOrigin3DDictOrListGpuIntegerCoordinate::ListGpuIntegerCoordinate(
+            coordinates,
+        )) if coordinates.len() > 3 => Err(ValidationError(format!(
+            "Origin has {} coordinates, but can't have more than 3",
+            coordinates.len()
+        ))),
+        _ => Ok(()),
     }
 }
 
 impl ToCore<wgpu_types::ImageDataLayout> for webgpu::GpuImageDataLayout {
-    fn to_core(self, _table: &ResourceTable) -> wgpu_types::ImageDataLayout {
-        wgpu_types::ImageDataLayout {
-            offset: self.offset.unwrap(),
+    fn to_core(self, _table: &ResourceTable) -> wasmtime::Result<wgpu_types::ImageDataLayout> {
+        Ok(wgpu_types::ImageDataLayout {
+            // source: https://www.w3.org/TR/webgpu/#dom-gpuimagedatalayout-offset
+            offset: self.offset.unwrap_or(0),
             bytes_per_row: self.bytes_per_row,
             rows_per_image: self.rows_per_image,
-        }
+        })
+    }
+}
+
+impl ToCore<wgpu_core::command::ImageCopyBuffer> for webgpu::GpuImageCopyBuffer {
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::ImageCopyBuffer> {
+        Ok(wgpu_core::command::ImageCopyBuffer {
+            buffer: table.get(&self.buffer)?.buffer,
+            // GPUImageCopyBuffer extends GPUImageDataLayout.
+            layout: webgpu::GpuImageDataLayout {
+                offset: self.offset,
+                bytes_per_row: self.bytes_per_row,
+                rows_per_image: self.rows_per_image,
+            }
+            .to_core(table)?,
+        })
     }
 }
 
 impl<'a> ToCore<wgpu_core::pipeline::ComputePipelineDescriptor<'a>>
     for webgpu::GpuComputePipelineDescriptor
 {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::pipeline::ComputePipelineDescriptor<'a> {
-        wgpu_core::pipeline::ComputePipelineDescriptor {
-            label: Default::default(), //TODO: enable: self.compute.label.map(|l| l.into()),
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::pipeline::ComputePipelineDescriptor<'a>> {
+        Ok(wgpu_core::pipeline::ComputePipelineDescriptor {
+            label: self.label.map(|l| l.into()),
             layout: match self.layout {
                 webgpu::GpuPipelineLayoutOrGpuAutoLayoutMode::GpuPipelineLayout(layout) => {
-                    Some(layout.to_core(table))
+                    Some(layout.to_core(table)?)
                 }
                 webgpu::GpuPipelineLayoutOrGpuAutoLayoutMode::GpuAutoLayoutMode(mode) => match mode
                 {
                     webgpu::GpuAutoLayoutMode::Auto => None,
                 },
             },
-            stage: self.compute.to_core(table),
-        }
+            stage: self.compute.to_core(table)?,
+        })
     }
 }
 
@@ -673,24 +1195,55 @@ impl<'a> ToCore<wgpu_core::pipeline::ProgrammableStageDescriptor<'a>>
     fn to_core(
         self,
         table: &ResourceTable,
-    ) -> wgpu_core::pipeline::ProgrammableStageDescriptor<'a> {
-        wgpu_core::pipeline::ProgrammableStageDescriptor {
-            module: self.module.to_core(table),
+    ) -> wasmtime::Result<wgpu_core::pipeline::ProgrammableStageDescriptor<'a>> {
+        Ok(wgpu_core::pipeline::ProgrammableStageDescriptor {
+            module: self.module.to_core(table)?,
             entry_point: self.entry_point.map(|ep| ep.into()),
             constants: Default::default(),
             zero_initialize_workgroup_memory: true,
-        }
+        })
     }
 }
 
 impl ToCore<wgpu_core::command::ComputePassTimestampWrites>
     for webgpu::GpuComputePassTimestampWrites
 {
-    fn to_core(self, table: &ResourceTable) -> wgpu_core::command::ComputePassTimestampWrites {
-        wgpu_core::command::ComputePassTimestampWrites {
-            query_set: self.query_set.to_core(table),
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::ComputePassTimestampWrites> {
+        Ok(wgpu_core::command::ComputePassTimestampWrites {
+            query_set: self.query_set.to_core(table)?,
             beginning_of_pass_write_index: self.beginning_of_pass_write_index,
             end_of_pass_write_index: self.end_of_pass_write_index,
-        }
+        })
+    }
+}
+
+impl ToCore<wgpu_core::command::RenderPassTimestampWrites>
+    for webgpu::GpuRenderPassTimestampWrites
+{
+    fn to_core(
+        self,
+        table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::command::RenderPassTimestampWrites> {
+        Ok(wgpu_core::command::RenderPassTimestampWrites {
+            query_set: self.query_set.to_core(table)?,
+            beginning_of_pass_write_index: self.beginning_of_pass_write_index,
+            end_of_pass_write_index: self.end_of_pass_write_index,
+        })
+    }
+}
+
+impl<'a> ToCore<wgpu_core::resource::QuerySetDescriptor<'a>> for webgpu::GpuQuerySetDescriptor {
+    fn to_core(
+        self,
+        _table: &ResourceTable,
+    ) -> wasmtime::Result<wgpu_core::resource::QuerySetDescriptor<'a>> {
+        Ok(wgpu_core::resource::QuerySetDescriptor {
+            label: self.label.map(|l| l.into()),
+            ty: self.type_.into(),
+            count: self.count,
+        })
     }
 }
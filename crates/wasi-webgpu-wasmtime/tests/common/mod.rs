@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use wasi_webgpu_wasmtime::wasi::webgpu::webgpu::{self, HostGpu};
+use wasi_webgpu_wasmtime::{MainThreadSpawner, WasiWebGpuImpl, WasiWebGpuView};
+use wasmtime::component::Resource;
+use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxBuilder, WasiView};
+
+pub struct HostState {
+    table: ResourceTable,
+    ctx: WasiCtx,
+    instance: Arc<wgpu_core::global::Global>,
+}
+
+impl WasiView for HostState {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+}
+
+struct InPlaceSpawner;
+
+impl MainThreadSpawner for InPlaceSpawner {
+    async fn spawn<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+    {
+        f()
+    }
+}
+
+impl WasiWebGpuView for HostState {
+    fn instance(&self) -> Arc<wgpu_core::global::Global> {
+        Arc::clone(&self.instance)
+    }
+
+    fn ui_thread_spawner(&self) -> Box<impl MainThreadSpawner + 'static> {
+        Box::new(InPlaceSpawner)
+    }
+}
+
+pub fn host() -> WasiWebGpuImpl<HostState> {
+    WasiWebGpuImpl(HostState {
+        table: ResourceTable::new(),
+        ctx: WasiCtxBuilder::new().build(),
+        instance: Arc::new(wgpu_core::global::Global::new(
+            "webgpu",
+            wgpu_types::InstanceDescriptor {
+                backends: wgpu_types::Backends::all(),
+                flags: wgpu_types::InstanceFlags::from_build_config(),
+                dx12_shader_compiler: wgpu_types::Dx12Compiler::Fxc,
+                gles_minor_version: wgpu_types::Gles3MinorVersion::default(),
+            },
+        )),
+    })
+}
+
+/// Tests calling this are `#[ignore]`d, run them with `cargo test -- --ignored` on a machine with a GPU.
+pub fn adapter(
+    host: &mut WasiWebGpuImpl<HostState>,
+) -> wasmtime::Result<Resource<webgpu::GpuAdapter>> {
+    let gpu = webgpu::Host::get_gpu(host)?;
+    Ok(host
+        .request_adapter(gpu, None)?
+        .expect("these tests need a GPU adapter"))
+}
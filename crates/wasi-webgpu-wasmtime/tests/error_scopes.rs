@@ -0,0 +1,49 @@
+mod common;
+
+use wasi_webgpu_wasmtime::wasi::webgpu::webgpu::{self, HostGpuAdapter, HostGpuDevice};
+use wasmtime::component::Resource;
+use wasmtime_wasi::WasiView;
+
+#[test]
+#[ignore = "needs a GPU adapter"]
+fn out_of_memory_scope_catches_huge_buffers() -> wasmtime::Result<()> {
+    let mut host = common::host();
+    let adapter = common::adapter(&mut host)?;
+    let limits = HostGpuAdapter::limits(&mut host, Resource::new_borrow(adapter.rep()))?;
+    let max_buffer_size = host.table().get(&limits)?.max_buffer_size;
+    let device = host
+        .request_device(
+            Resource::new_borrow(adapter.rep()),
+            Some(webgpu::GpuDeviceDescriptor {
+                required_features: None,
+                required_limits: Some(vec![("maxBufferSize".to_string(), max_buffer_size)]),
+                default_queue: None,
+                label: None,
+            }),
+        )?
+        .map_err(|_| wasmtime::Error::msg("Failed to request a device"))?;
+
+    host.push_error_scope(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuErrorFilter::OutOfMemory,
+    )?;
+    // Within the limit, but far more than any GPU has.
+    let size = max_buffer_size.min(1 << 50) & !3;
+    host.create_buffer(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuBufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu_types::BufferUsages::STORAGE.bits(),
+            mapped_at_creation: None,
+        },
+    )?;
+    let error = host
+        .pop_error_scope(Resource::new_borrow(device.rep()))?
+        .map_err(|_| wasmtime::Error::msg("The scope stack was empty"))?;
+    assert!(matches!(
+        error,
+        Some(webgpu::GpuError::GpuOutOfMemoryError(_))
+    ));
+    Ok(())
+}
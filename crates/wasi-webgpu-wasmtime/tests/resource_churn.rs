@@ -0,0 +1,311 @@
+mod common;
+
+use std::thread;
+use std::time::Duration;
+
+use common::HostState;
+use wasi_webgpu_wasmtime::wasi::webgpu::webgpu::{
+    self, HostGpuAdapter, HostGpuBindGroup, HostGpuBindGroupLayout, HostGpuBuffer,
+    HostGpuComputePipeline, HostGpuDevice, HostGpuPipelineLayout, HostGpuQueue,
+    HostGpuRenderPipeline, HostGpuSampler, HostGpuShaderModule, HostGpuTexture, HostGpuTextureView,
+};
+use wasi_webgpu_wasmtime::{WasiWebGpuImpl, WasiWebGpuView};
+use wasmtime::component::Resource;
+use wasmtime_wasi::WasiView;
+
+const SHADER: &str = "
+@group(0) @binding(0) var<uniform> color: vec4<f32>;
+
+@compute @workgroup_size(1)
+fn main_cs() {
+    _ = color;
+}
+
+@vertex
+fn main_vs() -> @builtin(position) vec4<f32> {
+    return color;
+}
+
+@fragment
+fn main_fs() -> @location(0) vec4<f32> {
+    return color;
+}
+";
+
+/// The number of live ids per resource type.
+fn live_counts(
+    instance: &wgpu_core::global::Global,
+    backend: wgpu_types::Backend,
+) -> Vec<(&'static str, usize)> {
+    // Frees resources whose last use was waiting on the device.
+    instance.poll_all_devices(true).unwrap();
+    let report = instance.generate_report();
+    let hub = report.hub_report(backend);
+    [
+        ("adapters", &hub.adapters),
+        ("devices", &hub.devices),
+        ("queues", &hub.queues),
+        ("textures", &hub.textures),
+        ("texture views", &hub.texture_views),
+        ("buffers", &hub.buffers),
+        ("samplers", &hub.samplers),
+        ("bind group layouts", &hub.bind_group_layouts),
+        ("bind groups", &hub.bind_groups),
+        ("shader modules", &hub.shader_modules),
+        ("pipeline layouts", &hub.pipeline_layouts),
+        ("compute pipelines", &hub.compute_pipelines),
+        ("render pipelines", &hub.render_pipelines),
+    ]
+    .into_iter()
+    .map(|(name, registry)| (name, registry.num_allocated))
+    .collect()
+}
+
+/// Runs `churn` 100 times and checks that it leaves no ids behind.
+fn assert_no_leaks(
+    host: &mut WasiWebGpuImpl<HostState>,
+    backend: wgpu_types::Backend,
+    mut churn: impl FnMut(&mut WasiWebGpuImpl<HostState>) -> wasmtime::Result<()>,
+) -> wasmtime::Result<()> {
+    // Warm up first, so ids that wgpu-core only hands out once aren't counted as leaks.
+    churn(host)?;
+    let before = live_counts(&host.instance(), backend);
+    for _ in 0..100 {
+        churn(host)?;
+    }
+    // Devices are released by their poller's thread, which may still be exiting.
+    let mut after = live_counts(&host.instance(), backend);
+    for _ in 0..100 {
+        if before == after {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+        after = live_counts(&host.instance(), backend);
+    }
+    assert_eq!(before, after);
+    Ok(())
+}
+
+fn device(
+    host: &mut WasiWebGpuImpl<HostState>,
+    adapter: &Resource<webgpu::GpuAdapter>,
+) -> wasmtime::Result<Resource<webgpu::GpuDevice>> {
+    host.request_device(Resource::new_borrow(adapter.rep()), None)?
+        .map_err(|_| wasmtime::Error::msg("Failed to request a device"))
+}
+
+fn backend(
+    host: &mut WasiWebGpuImpl<HostState>,
+    device: &Resource<webgpu::GpuDevice>,
+) -> wasmtime::Result<wgpu_types::Backend> {
+    Ok(host.table().get(device)?.adapter.backend())
+}
+
+fn uniform_bind_group_layout(
+    host: &mut WasiWebGpuImpl<HostState>,
+    device: &Resource<webgpu::GpuDevice>,
+) -> wasmtime::Result<Resource<webgpu::GpuBindGroupLayout>> {
+    host.create_bind_group_layout(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuBindGroupLayoutDescriptor {
+            entries: vec![webgpu::GpuBindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu_types::ShaderStages::all().bits(),
+                buffer: Some(webgpu::GpuBufferBindingLayout {
+                    type_: Some(webgpu::GpuBufferBindingType::Uniform),
+                    has_dynamic_offset: None,
+                    min_binding_size: None,
+                }),
+                sampler: None,
+                texture: None,
+                storage_texture: None,
+                external_texture: None,
+            }],
+            label: None,
+        },
+    )
+}
+
+fn churn_resources(
+    host: &mut WasiWebGpuImpl<HostState>,
+    device: &Resource<webgpu::GpuDevice>,
+) -> wasmtime::Result<()> {
+    let texture = host.create_texture(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuTextureDescriptor {
+            size: webgpu::GpuExtent3DDictOrListGpuIntegerCoordinate::GpuExtent3DDict(
+                webgpu::GpuExtent3DDict {
+                    width: 64,
+                    height: Some(64),
+                    depth_or_array_layers: None,
+                },
+            ),
+            mip_level_count: None,
+            sample_count: None,
+            dimension: webgpu::GpuTextureDimension::TwoD,
+            format: webgpu::GpuTextureFormat::Depth24plus,
+            usage: wgpu_types::TextureUsages::RENDER_ATTACHMENT.bits(),
+            view_formats: None,
+            label: None,
+        },
+    )?;
+    let view = host.create_view(Resource::new_borrow(texture.rep()), None)?;
+    let buffer = host.create_buffer(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuBufferDescriptor {
+            label: None,
+            size: 256,
+            usage: wgpu_types::BufferUsages::UNIFORM.bits(),
+            mapped_at_creation: None,
+        },
+    )?;
+    let sampler = host.create_sampler(Resource::new_borrow(device.rep()), None)?;
+    let bind_group_layout = uniform_bind_group_layout(host, device)?;
+    let bind_group = host.create_bind_group(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuBindGroupDescriptor {
+            layout: Resource::new_borrow(bind_group_layout.rep()),
+            entries: vec![webgpu::GpuBindGroupEntry {
+                binding: 0,
+                resource:
+                    webgpu::GpuBufferBindingOrGpuExternalTextureOrGpuSamplerOrGpuTextureView::GpuBufferBinding(
+                        webgpu::GpuBufferBinding {
+                            buffer: Resource::new_borrow(buffer.rep()),
+                            offset: None,
+                            size: None,
+                        },
+                    ),
+            }],
+            label: None,
+        },
+    )?;
+
+    HostGpuBindGroup::drop(host, bind_group)?;
+    HostGpuBindGroupLayout::drop(host, bind_group_layout)?;
+    HostGpuSampler::drop(host, sampler)?;
+    HostGpuBuffer::drop(host, buffer)?;
+    HostGpuTextureView::drop(host, view)?;
+    HostGpuTexture::drop(host, texture)?;
+    Ok(())
+}
+
+fn churn_pipelines(
+    host: &mut WasiWebGpuImpl<HostState>,
+    device: &Resource<webgpu::GpuDevice>,
+) -> wasmtime::Result<()> {
+    let shader_module = host.create_shader_module(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuShaderModuleDescriptor {
+            code: SHADER.to_string(),
+            compilation_hints: None,
+            label: None,
+        },
+    )?;
+    let bind_group_layout = uniform_bind_group_layout(host, device)?;
+    let pipeline_layout = host.create_pipeline_layout(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuPipelineLayoutDescriptor {
+            bind_group_layouts: vec![Resource::new_borrow(bind_group_layout.rep())],
+            label: None,
+        },
+    )?;
+    let compute_pipeline = host.create_compute_pipeline(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuComputePipelineDescriptor {
+            compute: webgpu::GpuProgrammableStage {
+                module: Resource::new_borrow(shader_module.rep()),
+                entry_point: Some("main_cs".to_string()),
+            },
+            layout: webgpu::GpuPipelineLayoutOrGpuAutoLayoutMode::GpuPipelineLayout(
+                Resource::new_borrow(pipeline_layout.rep()),
+            ),
+            label: None,
+        },
+    )?;
+    let render_pipeline = host.create_render_pipeline(
+        Resource::new_borrow(device.rep()),
+        webgpu::GpuRenderPipelineDescriptor {
+            vertex: webgpu::GpuVertexState {
+                buffers: None,
+                module: Resource::new_borrow(shader_module.rep()),
+                entry_point: "main_vs".to_string(),
+            },
+            primitive: None,
+            depth_stencil: None,
+            multisample: None,
+            fragment: Some(webgpu::GpuFragmentState {
+                targets: vec![Some(webgpu::GpuColorTargetState {
+                    format: webgpu::GpuTextureFormat::Rgba8unorm,
+                    blend: None,
+                    write_mask: None,
+                })],
+                module: Resource::new_borrow(shader_module.rep()),
+                entry_point: "main_fs".to_string(),
+            }),
+            layout: Some(Resource::new_borrow(pipeline_layout.rep())),
+            label: None,
+        },
+    )?;
+
+    HostGpuRenderPipeline::drop(host, render_pipeline)?;
+    HostGpuComputePipeline::drop(host, compute_pipeline)?;
+    HostGpuPipelineLayout::drop(host, pipeline_layout)?;
+    HostGpuBindGroupLayout::drop(host, bind_group_layout)?;
+    HostGpuShaderModule::drop(host, shader_module)?;
+    Ok(())
+}
+
+fn churn_devices(host: &mut WasiWebGpuImpl<HostState>) -> wasmtime::Result<()> {
+    let adapter = common::adapter(host)?;
+    let device = device(host, &adapter)?;
+    let queue = host.queue(Resource::new_borrow(device.rep()))?;
+
+    // Parents go first, their ids have to stay valid until the children are gone too.
+    HostGpuAdapter::drop(host, adapter)?;
+    HostGpuDevice::drop(host, device)?;
+    HostGpuQueue::drop(host, queue)?;
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs a GPU adapter"]
+fn dropped_resources_are_released() -> wasmtime::Result<()> {
+    let mut host = common::host();
+    let adapter = common::adapter(&mut host)?;
+    let device = device(&mut host, &adapter)?;
+    let backend = backend(&mut host, &device)?;
+
+    assert_no_leaks(&mut host, backend, |host| churn_resources(host, &device))?;
+
+    HostGpuDevice::drop(&mut host, device)?;
+    HostGpuAdapter::drop(&mut host, adapter)?;
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs a GPU adapter"]
+fn dropped_pipelines_are_released() -> wasmtime::Result<()> {
+    let mut host = common::host();
+    let adapter = common::adapter(&mut host)?;
+    let device = device(&mut host, &adapter)?;
+    let backend = backend(&mut host, &device)?;
+
+    assert_no_leaks(&mut host, backend, |host| churn_pipelines(host, &device))?;
+
+    HostGpuDevice::drop(&mut host, device)?;
+    HostGpuAdapter::drop(&mut host, adapter)?;
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs a GPU adapter"]
+fn dropped_devices_are_released() -> wasmtime::Result<()> {
+    let mut host = common::host();
+    let adapter = common::adapter(&mut host)?;
+    let device = device(&mut host, &adapter)?;
+    let backend = backend(&mut host, &device)?;
+    HostGpuDevice::drop(&mut host, device)?;
+    HostGpuAdapter::drop(&mut host, adapter)?;
+
+    assert_no_leaks(&mut host, backend, churn_devices)
+}
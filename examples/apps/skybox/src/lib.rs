@@ -190,7 +190,11 @@ impl Example {
     }
 
     fn init(width: u32, height: u32) -> Self {
-        let device = webgpu::get_gpu().request_adapter(None).request_device(None);
+        let device = webgpu::get_gpu()
+            .request_adapter(None)
+            .unwrap()
+            .request_device(None)
+            .unwrap();
         let canvas = mini_canvas::MiniCanvas::new(mini_canvas::CreateDesc {
             height,
             width,
@@ -313,7 +317,7 @@ impl Example {
         });
 
         let sky_pipeline = device.create_render_pipeline(&webgpu::GpuRenderPipelineDescriptor {
-            // label: Some("Sky"),
+            label: Some("Sky".into()),
             layout: Some(&pipeline_layout),
             vertex: webgpu::GpuVertexState {
                 module: &shader,
@@ -357,7 +361,7 @@ impl Example {
         });
 
         let entity_pipeline = device.create_render_pipeline(&webgpu::GpuRenderPipelineDescriptor {
-            // label: Some("Entity"),
+            label: Some("Entity".into()),
             // layout: None,
             layout: Some(&pipeline_layout),
             vertex: webgpu::GpuVertexState {
@@ -619,7 +623,12 @@ impl Example {
             rpass.set_pipeline(&self.entity_pipeline);
 
             for entity in self.entities.iter() {
-                rpass.set_vertex_buffer(0, &entity.vertex_buf.buffer, 0, entity.vertex_buf.size);
+                rpass.set_vertex_buffer(
+                    0,
+                    &entity.vertex_buf.buffer,
+                    None,
+                    Some(entity.vertex_buf.size),
+                );
                 rpass.draw(entity.vertex_count, 1, 0, 0);
             }
 
@@ -836,9 +845,7 @@ fn device_create_buffer_init(
         });
 
         let remote_buffer = buffer.get_mapped_range(None, None);
-        for i in 0..unpadded_size {
-            remote_buffer.set(i as u32, descriptor.contents[i as usize]);
-        }
+        remote_buffer.write(0, descriptor.contents);
 
         buffer.unmap();
         MyBuffer {
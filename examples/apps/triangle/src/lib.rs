@@ -37,8 +37,8 @@ fn fs_green() -> @location(0) vec4<f32> {
 
 fn draw_triangle() {
     let gpu = webgpu::get_gpu();
-    let adapter = gpu.request_adapter(None);
-    let device = adapter.request_device(None);
+    let adapter = gpu.request_adapter(None).unwrap();
+    let device = adapter.request_device(None).unwrap();
 
     let canvas = mini_canvas::MiniCanvas::new(mini_canvas::CreateDesc {
         height: 100,
@@ -116,6 +116,7 @@ fn draw_triangle() {
             depth_stencil: None,
             multisample: None,
             layout: None,
+            label: None,
         };
         let render_pipeline = device.create_render_pipeline(&pipeline_description);
         let pollables_res = wasi::io::poll::poll(&pollables);